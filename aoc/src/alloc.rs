@@ -0,0 +1,171 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Heap usage accounting
+//!
+//! [`Counting`] is a global allocator that wraps the system one and
+//! counts the allocations and the bytes in use. With the `alloc-stats`
+//! feature it's installed, and [`measure`] gets the [`Usage`] of a
+//! run, which `do_main` reports next to the times. The counters are
+//! global, so the usage includes what other threads allocate at the
+//! same time.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Held by the tests that use the counters, as [`count`] resets the peak
+#[cfg(test)]
+pub(crate) static COUNTERS: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Allocator that counts what goes through it
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Counting;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn grow(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+fn shrink(size: usize) {
+    CURRENT.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            // Counted as a new allocation that replaces the old one
+            shrink(layout.size());
+            grow(new_size);
+        }
+        new
+    }
+}
+
+/// Heap usage of a run
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    /// Number of allocations, including reallocations
+    pub allocations: usize,
+    /// Total size of the allocations
+    pub allocated: usize,
+    /// Largest heap size over the one at the start
+    pub peak: usize,
+}
+
+/// Size in bytes with a binary unit
+pub fn bytes(n: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if n < 1024 {
+        return format!("{} B", n);
+    }
+    let mut size = n as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocations ({})",
+            bytes(self.peak),
+            self.allocations,
+            bytes(self.allocated)
+        )
+    }
+}
+
+/// Whether [`Counting`] is the global allocator
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+fn count<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let base = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(base, Ordering::Relaxed);
+    let value = f();
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(base),
+    };
+    (value, usage)
+}
+
+/// Runs `f`, with its heap usage if [`enabled`]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    let (value, usage) = count(f);
+    (value, enabled().then_some(usage))
+}
+
+#[test]
+fn test_count() {
+    let _counters = COUNTERS.lock();
+    let ((), usage) = count(|| unsafe {
+        let layout = Layout::from_size_align(1000, 8).unwrap();
+        let ptr = Counting.alloc(layout);
+        assert!(!ptr.is_null());
+        let ptr = Counting.realloc(ptr, layout, 3000);
+        assert!(!ptr.is_null());
+        Counting.dealloc(ptr, Layout::from_size_align(3000, 8).unwrap());
+    });
+    // Other tests may be allocating and freeing at the same time if
+    // this is the global allocator
+    assert!(usage.allocations >= 2);
+    assert!(usage.allocated >= 4000);
+    assert!(usage.peak >= 3000);
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(bytes(1000), "1000 B");
+    assert_eq!(bytes(1536), "1.5 KiB");
+    assert_eq!(bytes(5 << 30), "5.0 GiB");
+    let usage = Usage {
+        allocations: 3,
+        allocated: 4096,
+        peak: 2048,
+    };
+    assert_eq!(usage.to_string(), "peak 2.0 KiB, 3 allocations (4.0 KiB)");
+}
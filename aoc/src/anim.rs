@@ -0,0 +1,327 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Animations of simulations
+//!
+//! Solvers push the states of their simulations to an [`Animation`]
+//! as [`Image`] frames, which go to a [`Sink`]. The sink comes from
+//! `AOC_ANIM`:
+//! - `term` or `term:<fps>`: plays the frames on the terminal (stderr);
+//! - `gif:<dir>` or `gif:<dir>:<fps>`: writes `<dir>/<name>.gif`.
+//!
+//! Without it, or while benchmarking, frames are not even built.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::render::{Image, Rgb};
+
+/// Environment variable with the sink of the animations
+pub const ENV: &str = "AOC_ANIM";
+
+/// Frames per second, by default
+pub const FPS: u32 = 10;
+
+/// Destination of the frames of an animation
+pub trait Sink {
+    fn push(&mut self, frame: &Image) -> Result<()>;
+
+    fn finish(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Sink that plays the frames on a terminal, using ANSI escapes to
+/// redraw it; each character has two cells, one over the other
+#[derive(Debug)]
+pub struct Terminal<W> {
+    out: W,
+    period: Duration,
+    last: Option<Instant>,
+}
+
+impl<W: Write> Terminal<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        Terminal {
+            out,
+            period: Duration::from_secs(1) / fps.max(1),
+            last: None,
+        }
+    }
+}
+
+impl<W: Write> Sink for Terminal<W> {
+    fn push(&mut self, frame: &Image) -> Result<()> {
+        let mut text = String::new();
+        if self.last.is_none() {
+            // Clear the screen and hide the cursor
+            text.push_str("\x1b[2J\x1b[?25l");
+        }
+        text.push_str("\x1b[H");
+        for y in (0..frame.height()).step_by(2) {
+            for x in 0..frame.width() {
+                let top = frame.get((x, y).into()).unwrap_or_default();
+                let bottom = frame.get((x, y + 1).into()).unwrap_or_default();
+                text.push_str(&format!(
+                    "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m\u{2580}",
+                    top.0, top.1, top.2, bottom.0, bottom.1, bottom.2
+                ));
+            }
+            text.push_str("\x1b[0m\n");
+        }
+        if let Some(last) = self.last {
+            thread::sleep(self.period.saturating_sub(last.elapsed()));
+        }
+        self.out.write_all(text.as_bytes())?;
+        self.out.flush()?;
+        self.last = Some(Instant::now());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if self.last.is_some() {
+            self.out.write_all(b"\x1b[0m\x1b[?25h")?;
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Sink that writes the frames as an animated GIF
+pub struct Gif<W: Write> {
+    out: Option<W>,
+    encoder: Option<gif::Encoder<W>>,
+    delay: u16,
+    scale: usize,
+    size: (usize, usize),
+}
+
+impl<W: Write> Gif<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        Gif {
+            out: Some(out),
+            encoder: None,
+            delay: (100 / fps.max(1)) as u16,
+            scale: 1,
+            size: (0, 0),
+        }
+    }
+}
+
+impl Gif<BufWriter<File>> {
+    pub fn create(path: &Path, fps: u32) -> Result<Self> {
+        let file = File::create(path).map_err(|e| eyre!("{}: {}", path.display(), e))?;
+        Ok(Gif::new(BufWriter::new(file), fps))
+    }
+}
+
+impl<W: Write> Sink for Gif<W> {
+    fn push(&mut self, frame: &Image) -> Result<()> {
+        let size = (frame.width(), frame.height());
+        if let Some(out) = self.out.take() {
+            // Around 800 pixels on the largest side
+            self.scale = (800 / size.0.max(size.1).max(1)).clamp(1, crate::render::SCALE);
+            self.size = size;
+            let (width, height) = (size.0 * self.scale, size.1 * self.scale);
+            let mut encoder = gif::Encoder::new(out, width as u16, height as u16, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            self.encoder = Some(encoder);
+        } else if size != self.size {
+            return Err(eyre!(
+                "frame of {}x{} in an animation of {}x{}",
+                size.0,
+                size.1,
+                self.size.0,
+                self.size.1
+            ));
+        }
+        let (width, height) = ((size.0 * self.scale) as u16, (size.1 * self.scale) as u16);
+        let pixels = frame.pixels(self.scale);
+        let mut palette = HashMap::<Rgb, u8>::new();
+        let mut indexes = Vec::with_capacity(pixels.len());
+        for pixel in &pixels {
+            let next = palette.len();
+            match palette.get(pixel) {
+                Some(i) => indexes.push(*i),
+                None if next < 256 => {
+                    palette.insert(*pixel, next as u8);
+                    indexes.push(next as u8);
+                }
+                None => break,
+            }
+        }
+        let mut gif_frame = if indexes.len() == pixels.len() {
+            let mut colours = vec![0; 3 * palette.len()];
+            for (Rgb(r, g, b), i) in palette {
+                colours[3 * i as usize..3 * i as usize + 3].copy_from_slice(&[r, g, b]);
+            }
+            gif::Frame::from_palette_pixels(width, height, indexes, colours, None)
+        } else {
+            let rgb = pixels
+                .iter()
+                .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+                .collect::<Vec<_>>();
+            gif::Frame::from_rgb_speed(width, height, &rgb, 10)
+        };
+        gif_frame.delay = self.delay;
+        if let Some(encoder) = &mut self.encoder {
+            encoder.write_frame(&gif_frame)?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        if let Some(encoder) = self.encoder.take() {
+            encoder.into_inner()?.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// Sink described by `spec`, in the syntax of [`ENV`]
+pub fn sink(spec: &str, name: &str) -> Result<Box<dyn Sink>> {
+    let (kind, args) = spec.split_once(':').unwrap_or((spec, ""));
+    match kind {
+        "term" => {
+            let fps = if args.is_empty() { FPS } else { args.parse()? };
+            Ok(Box::new(Terminal::new(io::stderr(), fps)))
+        }
+        "gif" if !args.is_empty() => {
+            let (dir, fps) = match args.rsplit_once(':') {
+                Some((dir, fps)) => (dir, fps.parse()?),
+                None => (args, FPS),
+            };
+            let path = PathBuf::from(dir).join(format!("{}.gif", name));
+            Ok(Box::new(Gif::create(&path, fps)?))
+        }
+        _ => Err(eyre!(
+            "invalid animation {}; use term[:<fps>] or gif:<dir>[:<fps>]",
+            spec
+        )),
+    }
+}
+
+/// Frames of a simulation, going to a [`Sink`] if there is one
+#[derive(Default)]
+pub struct Animation {
+    sink: Option<Box<dyn Sink>>,
+    error: Option<color_eyre::Report>,
+}
+
+impl Animation {
+    pub fn new(sink: Box<dyn Sink>) -> Animation {
+        Animation {
+            sink: Some(sink),
+            error: None,
+        }
+    }
+
+    /// Animation that discards the frames
+    pub fn none() -> Animation {
+        Animation::default()
+    }
+
+    /// Animation with the sink in [`ENV`], unless this is a benchmark;
+    /// `name` identifies it
+    pub fn from_env(name: &str) -> Result<Animation> {
+        match std::env::var(ENV) {
+            Ok(_) if crate::bench::is_running() => Ok(Animation::none()),
+            Ok(spec) => Ok(Animation::new(sink(&spec, name)?)),
+            Err(_) => Ok(Animation::none()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sink.is_some()
+    }
+
+    /// Pushes the frame built by `frame`, if there is a sink; errors are
+    /// kept for [`Animation::finish`], so that this can be used in the
+    /// step functions of simulations
+    pub fn frame(&mut self, frame: impl FnOnce() -> Image) {
+        if self.error.is_some() {
+            return;
+        }
+        if let Some(sink) = &mut self.sink {
+            if let Err(e) = sink.push(&frame()) {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    pub fn finish(mut self) -> Result<()> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        if let Some(sink) = &mut self.sink {
+            sink.finish()?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_terminal() -> Result<()> {
+    let mut out = vec![];
+    let mut terminal = Terminal::new(&mut out, 1000);
+    let mut image = Image::new(2, 3, Rgb::BLACK);
+    image.set((1, 0).into(), Rgb::WHITE);
+    terminal.push(&image)?;
+    terminal.push(&image)?;
+    terminal.finish()?;
+    let text = String::from_utf8(out)?;
+    assert_eq!(text.matches("\x1b[2J").count(), 1);
+    assert_eq!(text.matches("\x1b[H").count(), 2);
+    // 2 lines per frame
+    assert_eq!(text.matches('\n').count(), 4);
+    assert!(text.contains("\x1b[38;2;255;255;255m\x1b[48;2;0;0;0m\u{2580}"));
+    assert!(text.ends_with("\x1b[?25h"));
+    Ok(())
+}
+
+#[test]
+fn test_gif() -> Result<()> {
+    let mut out = vec![];
+    let mut gif = Gif::new(&mut out, 20);
+    let mut image = Image::new(3, 2, Rgb::BLACK);
+    gif.push(&image)?;
+    image.set((2, 1).into(), Rgb::RED);
+    gif.push(&image)?;
+    assert!(gif.push(&Image::new(2, 2, Rgb::BLACK)).is_err());
+    gif.finish()?;
+    drop(gif);
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(&out[..])?;
+    assert_eq!((decoder.width(), decoder.height()), (24, 16));
+    let mut frames = 0;
+    while let Some(frame) = decoder.read_next_frame()? {
+        assert_eq!(frame.delay, 5);
+        let last = &frame.buffer[frame.buffer.len() - 4..];
+        let expected = if frames == 0 { Rgb::BLACK } else { Rgb::RED };
+        assert_eq!(last, [expected.0, expected.1, expected.2, 255]);
+        frames += 1;
+    }
+    assert_eq!(frames, 2);
+    Ok(())
+}
+
+#[test]
+fn test_animation() -> Result<()> {
+    let mut animation = Animation::none();
+    animation.frame(|| unreachable!());
+    assert!(!animation.is_enabled());
+    animation.finish()?;
+    assert!(sink("term:x", "test").is_err());
+    assert!(sink("gif", "test").is_err());
+    assert!(sink("png:/tmp", "test").is_err());
+    Ok(())
+}
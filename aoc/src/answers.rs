@@ -0,0 +1,122 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Known answers, used to check solvers against real inputs
+//!
+//! The answers file has one entry per line, in the format
+//! `<day> <part> <input hash> <answer>`; empty lines and lines
+//! starting with `#` are ignored. The file is `answers.txt` in the
+//! current directory, or the one pointed to by `AOC_ANSWERS`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::solver::Part;
+
+/// Hash of the input, in hex: 64-bit FNV-1a, which is stable across
+/// platforms and compiler versions.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+pub fn answers_path() -> PathBuf {
+    std::env::var_os("AOC_ANSWERS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("answers.txt"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Verdict {
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail(_))
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u32, Part, String), String>);
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Answers> {
+        let mut answers = Answers::default();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields = line.splitn(4, ' ').collect::<Vec<_>>();
+            let &[day, part, hash, answer] = fields.as_slice() else {
+                return Err(eyre!("answers line {}: invalid entry {:?}", i + 1, line));
+            };
+            let key = (day.parse()?, part.parse()?, hash.to_string());
+            answers.0.insert(key, answer.to_string());
+        }
+        Ok(answers)
+    }
+
+    /// Loads the answers file; a missing file has no answers.
+    pub fn load(path: &Path) -> Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(eyre!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn check(&self, day: u32, part: Part, hash: &str, answer: &str) -> Verdict {
+        match self.0.get(&(day, part, hash.to_string())) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.clone()),
+        }
+    }
+}
+
+#[test]
+fn test_input_hash() {
+    assert_eq!(input_hash(""), "cbf29ce484222325");
+    assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+}
+
+#[test]
+fn test_check() -> Result<()> {
+    let answers = Answers::parse("# comment\n\n1 a 0123 142\n1 b 0123 two words\n")?;
+    assert_eq!(answers.check(1, Part::A, "0123", "142"), Verdict::Pass);
+    assert_eq!(
+        answers.check(1, Part::A, "0123", "143"),
+        Verdict::Fail("142".to_string())
+    );
+    assert_eq!(
+        answers.check(1, Part::B, "0123", "two words"),
+        Verdict::Pass
+    );
+    assert_eq!(answers.check(1, Part::A, "4567", "142"), Verdict::Unknown);
+    assert!(Answers::parse("1 a 0123\n").is_err());
+    Ok(())
+}
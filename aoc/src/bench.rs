@@ -0,0 +1,188 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Benchmark of solvers, with parsing and solving timed separately
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::alloc::{self, Usage};
+use crate::solver::{Answer, DynSolver, Part};
+
+pub fn duration(d: Duration) -> String {
+    format!("{}", humantime::Duration::from(d))
+}
+
+/// Summary of a set of time samples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn single(sample: Duration) -> Stats {
+        Stats {
+            min: sample,
+            median: sample,
+            p95: sample,
+        }
+    }
+
+    pub fn new(samples: &[Duration]) -> Result<Stats> {
+        if samples.is_empty() {
+            return Err(eyre!("no samples"));
+        }
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = samples[(len * 95).div_ceil(100) - 1];
+        Ok(Stats {
+            min: samples[0],
+            median,
+            p95,
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {}, median {}, p95 {}",
+            duration(self.min),
+            duration(self.median),
+            duration(self.p95)
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub answer: Answer,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// Heap usage of the last run, if it's being counted
+    pub memory: Option<Usage>,
+}
+
+static RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Whether a benchmark is running; solvers skip their side outputs,
+/// like images and animations, so that they are not redone on every
+/// run nor timed
+pub fn is_running() -> bool {
+    RUNNING.load(Ordering::Relaxed)
+}
+
+/// Sets [`is_running`] until dropped
+struct Running;
+
+impl Running {
+    fn start() -> Running {
+        RUNNING.store(true, Ordering::Relaxed);
+        Running
+    }
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        RUNNING.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Number of untimed runs done before a benchmark of `runs` runs
+pub fn warmup(runs: usize) -> usize {
+    runs.div_ceil(10)
+}
+
+/// Runs the solver `runs` times on the input, after the warm-up
+pub fn bench(solver: &dyn DynSolver, part: Part, input: &str, runs: usize) -> Result<Bench> {
+    if runs == 0 {
+        return Err(eyre!("number of benchmark runs must be positive"));
+    }
+    let _running = Running::start();
+    for _ in 0..warmup(runs) {
+        solver.run_timed(part, &mut input.as_bytes())?;
+    }
+    let mut parse = Vec::with_capacity(runs);
+    let mut solve = Vec::with_capacity(runs);
+    let mut answer = None;
+    let mut memory = None;
+    for _ in 0..runs {
+        let (timed, usage) = alloc::measure(|| solver.run_timed(part, &mut input.as_bytes()));
+        let timed = timed?;
+        parse.push(timed.parse);
+        solve.push(timed.solve);
+        answer = Some(timed.answer);
+        memory = usage;
+    }
+    Ok(Bench {
+        answer: answer.expect("runs is positive"),
+        runs,
+        parse: Stats::new(&parse)?,
+        solve: Stats::new(&solve)?,
+        memory,
+    })
+}
+
+#[test]
+fn test_stats() -> Result<()> {
+    let ms = |v: &[u64]| {
+        v.iter()
+            .map(|&m| Duration::from_millis(m))
+            .collect::<Vec<_>>()
+    };
+    let stats = Stats::new(&ms(&[5, 1, 3]))?;
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_millis(3));
+    assert_eq!(stats.p95, Duration::from_millis(5));
+    let stats = Stats::new(&ms(&(1..=20).rev().collect::<Vec<_>>()))?;
+    assert_eq!(stats.min, Duration::from_millis(1));
+    assert_eq!(stats.median, Duration::from_micros(10500));
+    assert_eq!(stats.p95, Duration::from_millis(19));
+    assert!(Stats::new(&[]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_bench() -> Result<()> {
+    use crate::solver::Solver;
+    struct Probe;
+    impl Solver for Probe {
+        const DAY: u32 = 0;
+        type Input = ();
+        fn parse(&self, _bufin: impl std::io::BufRead) -> Result<()> {
+            Ok(())
+        }
+        fn unparse(&self, _input: &()) -> String {
+            String::new()
+        }
+        fn part1(&self, _input: ()) -> Result<Answer> {
+            Ok(is_running().to_string().into())
+        }
+        fn part2(&self, _input: ()) -> Result<Answer> {
+            Err(eyre!("fails"))
+        }
+    }
+    let _counters = crate::alloc::COUNTERS.lock();
+    let result = bench(&Probe, Part::A, "", 3)?;
+    assert_eq!(result.answer.to_string(), "true");
+    assert!(!is_running());
+    assert!(bench(&Probe, Part::B, "", 3).is_err());
+    assert!(!is_running());
+    Ok(())
+}
@@ -0,0 +1,132 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Cycle detection for simulations
+//!
+//! A simulation with a finite number of states eventually repeats one
+//! of them, and from then on goes around the same cycle forever.
+//! [`find`] runs it until that happens, keeping the states it went
+//! through, which is enough to get the state at any iteration;
+//! [`nth`] does that for a single iteration.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// States of a simulation, up to the first repeated one
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    states: Vec<S>,
+    start: usize,
+}
+
+impl<S> Cycle<S> {
+    /// Iteration where the cycle starts
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Length of the cycle
+    pub fn period(&self) -> usize {
+        self.states.len() - self.start
+    }
+
+    /// State after `n` iterations
+    pub fn nth(&self, n: usize) -> &S {
+        if n < self.states.len() {
+            &self.states[n]
+        } else {
+            &self.states[self.start + (n - self.start) % self.period()]
+        }
+    }
+}
+
+/// Runs `step` on its own results from `initial` until a state repeats,
+/// or until there are more than `limit` states
+fn run<S, F>(initial: S, mut step: F, limit: usize) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    while states.len() <= limit {
+        match seen.entry(state.clone()) {
+            Entry::Occupied(e) => {
+                return Cycle {
+                    states,
+                    start: *e.get(),
+                }
+            }
+            Entry::Vacant(e) => {
+                e.insert(states.len());
+            }
+        }
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+    let start = states.len();
+    Cycle { states, start }
+}
+
+/// Runs `step` on its own results from `initial` until it finds the
+/// cycle; doesn't return if the number of states is not finite
+pub fn find<S, F>(initial: S, step: F) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    run(initial, step, usize::MAX)
+}
+
+/// State after `n` iterations of `step` from `initial`, skipping the
+/// repetitions of the cycle once it's found
+pub fn nth<S, F>(initial: S, step: F, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    run(initial, step, n).nth(n).clone()
+}
+
+#[test]
+fn test_find() {
+    // 0, 1, 2, 5, 26, 677 % 100 = 77, ...
+    let cycle = find(0_u32, |x| (x * x + 1) % 100);
+    let mut x = 0;
+    for n in 0..1000 {
+        assert_eq!(*cycle.nth(n), x, "n {}", n);
+        x = (x * x + 1) % 100;
+    }
+    assert_eq!(
+        *cycle.nth(cycle.start()),
+        *cycle.nth(cycle.start() + cycle.period())
+    );
+    assert_ne!(
+        *cycle.nth(cycle.start() - 1),
+        *cycle.nth(cycle.start() + cycle.period() - 1)
+    );
+    // Pure cycle
+    let cycle = find(0_u32, |x| (x + 1) % 7);
+    assert_eq!((cycle.start(), cycle.period()), (0, 7));
+    assert_eq!(*cycle.nth(1_000_000_000), 1_000_000_000 % 7);
+    // Fixed point
+    let cycle = find(10_u32, |x| x / 2);
+    assert_eq!((cycle.start(), cycle.period()), (4, 1));
+    assert_eq!(*cycle.nth(1_000_000_000), 0);
+}
+
+#[test]
+fn test_nth() {
+    let step = |x: &u64| (x * 3 + 1) % 1000;
+    let mut x = 5;
+    for n in 0..500 {
+        assert_eq!(nth(5, step, n), x, "n {}", n);
+        x = step(&x);
+    }
+    // Stops as soon as it gets to n, even without a cycle
+    assert_eq!(nth(0_u64, |x| x + 1, 1000), 1000);
+}
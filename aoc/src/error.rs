@@ -0,0 +1,111 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Errors of the solvers
+//!
+//! [`Error`] tells bad input apart from inputs the solver can't handle.
+//! It converts into a [`Report`] with `?` like any other error, and
+//! [`is_bad_input`] finds it back in a report.
+
+use std::fmt;
+
+use color_eyre::Report;
+
+use crate::parser::InputError;
+use crate::solver::Part;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Input that doesn't follow the format of the puzzle
+    Parse(InputError),
+    /// Character that isn't any of the values of `what`, like the cells
+    /// of a grid
+    InvalidChar { what: &'static str, c: char },
+    /// Input with the wrong dimensions, like rows of different lengths
+    Shape(String),
+    /// Valid input for which the solver found no answer
+    NoSolution(String),
+    /// Solver that gave up after `limit` of `what`
+    Limit { what: &'static str, limit: u64 },
+    /// Part that the day doesn't have, see [`crate::Solver::PARTS`]
+    NoPart { day: u32, part: Part },
+}
+
+impl Error {
+    pub fn invalid_char(what: &'static str, c: char) -> Error {
+        Error::InvalidChar { what, c }
+    }
+
+    pub fn shape(message: impl Into<String>) -> Error {
+        Error::Shape(message.into())
+    }
+
+    pub fn no_solution(reason: impl Into<String>) -> Error {
+        Error::NoSolution(reason.into())
+    }
+
+    pub fn limit(what: &'static str, limit: u64) -> Error {
+        Error::Limit { what, limit }
+    }
+
+    pub fn no_part(day: u32, part: Part) -> Error {
+        Error::NoPart { day, part }
+    }
+
+    /// Whether the error is in the input, rather than in the solver
+    pub fn is_bad_input(&self) -> bool {
+        matches!(
+            self,
+            Error::Parse(_) | Error::InvalidChar { .. } | Error::Shape(_)
+        )
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::InvalidChar { what, c } => write!(f, "invalid {} {}", what, c),
+            Error::Shape(message) => write!(f, "invalid input shape: {}", message),
+            Error::NoSolution(reason) => write!(f, "no solution: {}", reason),
+            Error::Limit { what, limit } => write!(f, "gave up after {} {}", limit, what),
+            Error::NoPart { day, part } => write!(f, "day {} has no part {}", day, part),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<InputError> for Error {
+    fn from(e: InputError) -> Error {
+        Error::Parse(e)
+    }
+}
+
+/// Whether `report` comes from an [`Error`] in the input
+pub fn is_bad_input(report: &Report) -> bool {
+    report
+        .chain()
+        .filter_map(|e| e.downcast_ref::<Error>())
+        .any(Error::is_bad_input)
+}
+
+#[test]
+fn test_error() {
+    let report = Report::from(Error::invalid_char("cell", 'x'));
+    assert_eq!(report.to_string(), "invalid cell x");
+    assert!(is_bad_input(&report));
+    let report = Report::from(Error::shape("row 1 has 2 cells, expected 3"));
+    assert!(is_bad_input(&report.wrap_err("day14a")));
+    let report = Report::from(Error::no_solution("goal is not reachable"));
+    assert_eq!(report.to_string(), "no solution: goal is not reachable");
+    assert!(!is_bad_input(&report));
+    let report = Report::from(Error::limit("button presses", 1000));
+    assert_eq!(report.to_string(), "gave up after 1000 button presses");
+    assert!(!is_bad_input(&report));
+    let report = Report::from(Error::no_part(25, Part::B));
+    assert_eq!(report.to_string(), "day 25 has no part b");
+    assert!(!is_bad_input(&report));
+    assert!(!is_bad_input(&color_eyre::eyre::eyre!("other")));
+}
@@ -0,0 +1,198 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Examples of the puzzle statements, with their expected answers
+//!
+//! Each day lists its examples with the [`examples!`](crate::examples!)
+//! macro, which defines an `examples()` registry and a test that runs
+//! all of them and checks that every part of the day has at least one.
+//! The macro also tests that each example input round-trips through
+//! [`Solver::parse`] and [`Solver::unparse`].
+
+use std::fmt::{self, Debug};
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::solver::{Answer, DynSolver, Part, Solver};
+
+pub struct Example {
+    /// Solver with the parameters of the example
+    pub solver: Box<dyn DynSolver>,
+    pub part: Part,
+    pub input: &'static str,
+    pub answer: Answer,
+}
+
+impl Example {
+    pub fn new<S: Solver + 'static>(
+        solver: S,
+        part: Part,
+        input: &'static str,
+        answer: impl Into<Answer>,
+    ) -> Example {
+        Example {
+            solver: Box::new(solver),
+            part,
+            input,
+            answer: answer.into(),
+        }
+    }
+
+    pub fn check(&self) -> Result<()> {
+        let answer = self.solver.run(self.part, &mut self.input.as_bytes())?;
+        if answer != self.answer {
+            return Err(eyre!("got {}, expected {}", answer, self.answer));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Example {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} => {}", self.solver.name(self.part), self.answer)
+    }
+}
+
+/// Checks all examples, reporting every failure, and that each part
+/// in `parts` is covered, except the `untested` ones
+pub fn check_all(parts: &[Part], untested: &[Part], examples: &[Example]) -> Result<()> {
+    let mut errors = vec![];
+    for (i, example) in examples.iter().enumerate() {
+        if let Err(e) = example.check() {
+            errors.push(format!("example {} ({:?}): {}", i, example, e));
+        }
+    }
+    for part in parts.iter().filter(|p| !untested.contains(p)) {
+        if !examples.iter().any(|e| e.part == *part) {
+            errors.push(format!("part {} has no examples", part));
+        }
+    }
+    if !errors.is_empty() {
+        return Err(eyre!(errors.join("\n")));
+    }
+    Ok(())
+}
+
+/// Checks that `input` is written back unchanged by `unparse`, and
+/// that parsing the result gives the same value
+pub fn check_roundtrip<S>(solver: &S, input: &str) -> Result<()>
+where
+    S: Solver,
+    S::Input: PartialEq + Debug,
+{
+    let parsed = solver.parse(input.as_bytes())?;
+    let unparsed = solver.unparse(&parsed);
+    let reparsed = solver.parse(unparsed.as_bytes())?;
+    if reparsed != parsed {
+        return Err(eyre!(
+            "parse(unparse(x)) != x:\n{:?}\n{:?}",
+            parsed,
+            reparsed
+        ));
+    }
+    if unparsed != input {
+        return Err(eyre!("unparse changed the input:\n{}", unparsed));
+    }
+    Ok(())
+}
+
+/// Defines the `examples()` registry of a day and the test that
+/// checks them
+///
+/// Each entry has the part, optionally the solver with the parameters
+/// of the example (the default solver is used otherwise), the input
+/// and the expected answer. Parts that have no example in the puzzle
+/// statement are listed after the solver, as in `Day20, untested: [B];`.
+///
+/// ```ignore
+/// aoc::examples! {
+///     Day11;
+///     A: EXAMPLE => 374;
+///     B(Day11 { inc: 9 }): EXAMPLE => 1030;
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($solver:ty $(, untested: [$($untested:ident),*])?; $($part:ident $(($params:expr))?: $input:expr => $answer:expr;)*) => {
+        pub fn examples() -> Vec<$crate::examples::Example> {
+            vec![$(
+                $crate::examples::Example::new(
+                    $crate::examples_solver!($solver $(, $params)?),
+                    $crate::Part::$part,
+                    $input,
+                    $answer,
+                ),
+            )*]
+        }
+
+        #[test]
+        fn test_examples() -> $crate::Result<()> {
+            $crate::examples::check_all(
+                <$solver as $crate::Solver>::PARTS,
+                &[$($($crate::Part::$untested),*)?],
+                &examples(),
+            )
+        }
+
+        #[test]
+        fn test_roundtrip() -> $crate::Result<()> {
+            for input in [$($input),*] {
+                $crate::examples::check_roundtrip(&<$solver>::default(), input)?;
+            }
+            Ok(())
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! examples_solver {
+    ($solver:ty) => {
+        <$solver>::default()
+    };
+    ($solver:ty, $params:expr) => {
+        $params
+    };
+}
+
+#[test]
+fn test_check_all() -> Result<()> {
+    #[derive(Default)]
+    struct Len;
+    impl Solver for Len {
+        const DAY: u32 = 0;
+        type Input = String;
+        fn parse(&self, mut bufin: impl std::io::BufRead) -> Result<String> {
+            let mut input = String::new();
+            bufin.read_to_string(&mut input)?;
+            Ok(input)
+        }
+        fn unparse(&self, input: &String) -> String {
+            input.clone()
+        }
+        fn part1(&self, input: String) -> Result<Answer> {
+            Ok(input.len().into())
+        }
+        fn part2(&self, input: String) -> Result<Answer> {
+            Ok(input.trim().into())
+        }
+    }
+    let parts = <Len as Solver>::PARTS;
+    let ok = [
+        Example::new(Len, Part::A, "abc", 3),
+        Example::new(Len, Part::B, " x ", "x"),
+    ];
+    check_all(parts, &[], &ok)?;
+    let bad = [
+        Example::new(Len, Part::A, "abc", 3),
+        Example::new(Len, Part::A, "", 1),
+    ];
+    assert_eq!(
+        check_all(parts, &[], &bad).unwrap_err().to_string(),
+        "example 1 (day00a => 1): got 0, expected 1\npart b has no examples"
+    );
+    assert!(check_all(parts, &[Part::B], &bad[..1]).is_ok());
+    Ok(())
+}
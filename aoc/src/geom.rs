@@ -0,0 +1,401 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Integer geometry: points, directions, polygons and segments
+//!
+//! Coordinates follow the grids: `x` grows to the east and `y` to the
+//! south. Everything is exact; areas are kept doubled and
+//! intersections are [`Frac`]s.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+pub use sqrid::Dir;
+
+use crate::grid::Pos;
+
+/// Point or vector in the plane
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Neighbor in the given direction
+    pub fn step(&self, dir: Dir) -> Point2 {
+        *self + Point2::from(dir)
+    }
+
+    /// Point `n` steps away in the given direction
+    pub fn go(&self, dir: Dir, n: i64) -> Point2 {
+        *self + Point2::from(dir) * n
+    }
+
+    /// Z component of the cross product
+    pub fn cross(&self, other: &Point2) -> i128 {
+        self.x as i128 * other.y as i128 - self.y as i128 * other.x as i128
+    }
+}
+
+/// Unit vector of the direction; diagonals have both components set
+impl From<Dir> for Point2 {
+    fn from(dir: Dir) -> Point2 {
+        let (x, y) = match dir {
+            Dir::N => (0, -1),
+            Dir::NE => (1, -1),
+            Dir::E => (1, 0),
+            Dir::SE => (1, 1),
+            Dir::S => (0, 1),
+            Dir::SW => (-1, 1),
+            Dir::W => (-1, 0),
+            Dir::NW => (-1, -1),
+        };
+        Point2 { x, y }
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Point2 {
+        Point2 { x, y }
+    }
+}
+
+impl From<Pos> for Point2 {
+    fn from(pos: Pos) -> Point2 {
+        Point2::new(pos.x as i64, pos.y as i64)
+    }
+}
+
+impl Add for Point2 {
+    type Output = Point2;
+    fn add(self, other: Point2) -> Point2 {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point2 {
+    fn add_assign(&mut self, other: Point2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Point2;
+    fn sub(self, other: Point2) -> Point2 {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point2 {
+    type Output = Point2;
+    fn mul(self, k: i64) -> Point2 {
+        Point2::new(self.x * k, self.y * k)
+    }
+}
+
+impl Neg for Point2 {
+    type Output = Point2;
+    fn neg(self) -> Point2 {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Point or vector in space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// Projection on the `xy` plane
+    pub fn xy(&self) -> Point2 {
+        Point2::new(self.x, self.y)
+    }
+}
+
+impl From<(i64, i64, i64)> for Point3 {
+    fn from((x, y, z): (i64, i64, i64)) -> Point3 {
+        Point3 { x, y, z }
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<i64> for Point3 {
+    type Output = Point3;
+    fn mul(self, k: i64) -> Point3 {
+        Point3::new(self.x * k, self.y * k, self.z * k)
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Twice the area of the polygon, by the shoelace formula
+///
+/// The vertices can be in either orientation, and the last one is
+/// connected to the first.
+pub fn double_area(vertices: &[Point2]) -> i64 {
+    let n = vertices.len();
+    let sum = (0..n)
+        .map(|i| vertices[i].cross(&vertices[(i + 1) % n]))
+        .sum::<i128>();
+    sum.abs() as i64
+}
+
+/// Number of lattice points on the edges of the polygon
+pub fn boundary_points(vertices: &[Point2]) -> i64 {
+    let n = vertices.len();
+    (0..n)
+        .map(|i| {
+            let d = vertices[(i + 1) % n] - vertices[i];
+            gcd(d.x, d.y)
+        })
+        .sum()
+}
+
+/// Number of lattice points strictly inside the polygon, by Pick's
+/// theorem
+pub fn interior_points(vertices: &[Point2]) -> i64 {
+    (double_area(vertices) - boundary_points(vertices) + 2) / 2
+}
+
+/// Sign of the turn `a` -> `b` -> `c`: positive if `c` is to the
+/// left of `a` -> `b` with `y` pointing up, zero if they are collinear
+pub fn orientation(a: Point2, b: Point2, c: Point2) -> i128 {
+    (b - a).cross(&(c - a)).signum()
+}
+
+/// Closed segment from `a` to `b`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub a: Point2,
+    pub b: Point2,
+}
+
+impl Segment {
+    pub fn new(a: Point2, b: Point2) -> Segment {
+        Segment { a, b }
+    }
+
+    /// Whether `p` is on the segment
+    pub fn contains(&self, p: Point2) -> bool {
+        orientation(self.a, self.b, p) == 0
+            && p.x >= self.a.x.min(self.b.x)
+            && p.x <= self.a.x.max(self.b.x)
+            && p.y >= self.a.y.min(self.b.y)
+            && p.y <= self.a.y.max(self.b.y)
+    }
+
+    /// Whether the segments share at least one point, including
+    /// touching endpoints and collinear overlaps
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let o1 = orientation(self.a, self.b, other.a);
+        let o2 = orientation(self.a, self.b, other.b);
+        let o3 = orientation(other.a, other.b, self.a);
+        let o4 = orientation(other.a, other.b, self.b);
+        (o1 * o2 < 0 && o3 * o4 < 0)
+            || self.contains(other.a)
+            || self.contains(other.b)
+            || other.contains(self.a)
+            || other.contains(self.b)
+    }
+}
+
+/// Exact fraction, with a positive denominator
+#[derive(Debug, Clone, Copy)]
+pub struct Frac {
+    pub num: i128,
+    pub den: i128,
+}
+
+impl Frac {
+    pub fn new(num: i128, den: i128) -> Frac {
+        assert!(den != 0, "zero denominator");
+        if den < 0 {
+            Frac::new(-num, -den)
+        } else {
+            Frac { num, den }
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.num < 0
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.num as f64 / self.den as f64
+    }
+}
+
+impl From<i64> for Frac {
+    fn from(i: i64) -> Frac {
+        Frac::new(i.into(), 1)
+    }
+}
+
+impl Ord for Frac {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.num * other.den).cmp(&(other.num * self.den))
+    }
+}
+
+impl PartialOrd for Frac {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Frac {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Frac {}
+
+/// Line through `p` with direction `v`: the points `p + v * t`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line {
+    pub p: Point2,
+    pub v: Point2,
+}
+
+impl Line {
+    pub fn new(p: Point2, v: Point2) -> Line {
+        Line { p, v }
+    }
+
+    /// Coordinates of the point at `t`
+    pub fn at(&self, t: Frac) -> (Frac, Frac) {
+        let coord = |p: i64, v: i64| Frac::new(p as i128 * t.den + v as i128 * t.num, t.den);
+        (coord(self.p.x, self.v.x), coord(self.p.y, self.v.y))
+    }
+
+    /// Parameters `(t, u)` of the point where the lines meet, such
+    /// that `self.at(t) == other.at(u)`; `None` if they are parallel
+    pub fn intersection(&self, other: &Line) -> Option<(Frac, Frac)> {
+        let den = self.v.cross(&other.v);
+        if den == 0 {
+            return None;
+        }
+        let d = other.p - self.p;
+        Some((
+            Frac::new(d.cross(&other.v), den),
+            Frac::new(d.cross(&self.v), den),
+        ))
+    }
+}
+
+#[test]
+fn test_point() {
+    let p = Point2::new(1, 2);
+    assert_eq!(p.step(Dir::N), Point2::new(1, 1));
+    assert_eq!(p.go(Dir::SE, 3), Point2::new(4, 5));
+    assert_eq!(p.manhattan(&Point2::new(-2, 6)), 7);
+    assert_eq!(-p + Point2::from((3, 3)), Point2::new(2, 1));
+    assert_eq!(Point2::from(Pos::new(3, 4)), Point2::new(3, 4));
+    let q = Point3::new(1, 2, 3);
+    assert_eq!((q * 2 - q).manhattan(&Point3::ORIGIN), 6);
+    assert_eq!(q.xy(), p);
+}
+
+#[test]
+fn test_polygon() {
+    // 3x2 rectangle, in both orientations
+    let mut rect = vec![(0, 0), (3, 0), (3, 2), (0, 2)]
+        .into_iter()
+        .map(Point2::from)
+        .collect::<Vec<_>>();
+    assert_eq!(double_area(&rect), 12);
+    assert_eq!(boundary_points(&rect), 10);
+    assert_eq!(interior_points(&rect), 2);
+    rect.reverse();
+    assert_eq!(double_area(&rect), 12);
+    let triangle = [Point2::new(0, 0), Point2::new(4, 0), Point2::new(0, 4)];
+    assert_eq!(double_area(&triangle), 16);
+    assert_eq!(boundary_points(&triangle), 12);
+    assert_eq!(interior_points(&triangle), 3);
+}
+
+#[test]
+fn test_segment() {
+    let s = |a: (i64, i64), b: (i64, i64)| Segment::new(a.into(), b.into());
+    assert!(s((0, 0), (4, 4)).intersects(&s((0, 4), (4, 0))));
+    assert!(s((0, 0), (4, 4)).intersects(&s((4, 4), (5, 0))));
+    assert!(s((0, 0), (4, 0)).intersects(&s((2, 0), (6, 0))));
+    assert!(!s((0, 0), (4, 0)).intersects(&s((5, 0), (6, 0))));
+    assert!(!s((0, 0), (4, 4)).intersects(&s((1, 0), (5, 4))));
+    assert!(s((0, 0), (2, 2)).intersects(&s((0, 4), (4, 0))));
+    assert!(!s((0, 0), (1, 1)).intersects(&s((0, 4), (4, 0))));
+}
+
+#[test]
+fn test_line() {
+    let a = Line::new(Point2::new(0, 0), Point2::new(2, 2));
+    let b = Line::new(Point2::new(0, 3), Point2::new(1, -1));
+    let (t, u) = a.intersection(&b).unwrap();
+    assert_eq!((t, u), (Frac::new(3, 4), Frac::new(3, 2)));
+    assert_eq!(a.at(t), b.at(u));
+    assert_eq!(a.at(t).0, Frac::new(3, 2));
+    assert_eq!(Frac::new(1, -2).to_f64(), -0.5);
+    assert!(Frac::new(1, -2).is_negative());
+    assert_eq!(
+        a.intersection(&Line::new(Point2::new(1, 0), Point2::new(-3, -3))),
+        None
+    );
+}
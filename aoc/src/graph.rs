@@ -0,0 +1,446 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Graphs and search algorithms
+//!
+//! [`Graph`] gives compact ids to the nodes and keeps adjacency lists
+//! of edges, with a value each (a weight or a label). The searches
+//! take a successors function instead, so that they also work on
+//! graphs whose nodes are generated on the fly, like the states of a
+//! walk on a grid; for a [`Graph`], use its ids and
+//! [`Graph::neighbors`] or [`Graph::edges`].
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+pub type NodeId = usize;
+
+/// Directed graph with values of type `E` in the edges
+#[derive(Debug, Clone)]
+pub struct Graph<N, E = ()> {
+    nodes: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<Vec<(NodeId, E)>>,
+}
+
+impl<N, E> Default for Graph<N, E> {
+    fn default() -> Self {
+        Graph {
+            nodes: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, E> Graph<N, E> {
+    pub fn new() -> Graph<N, E> {
+        Graph::default()
+    }
+
+    /// Id of the node, adding it if it's new
+    pub fn add_node(&mut self, node: N) -> NodeId {
+        match self.ids.entry(node) {
+            Entry::Occupied(e) => *e.get(),
+            Entry::Vacant(e) => {
+                self.nodes.push(e.key().clone());
+                self.edges.push(vec![]);
+                *e.insert(self.nodes.len() - 1)
+            }
+        }
+    }
+
+    pub fn id(&self, node: &N) -> Option<NodeId> {
+        self.ids.get(node).copied()
+    }
+
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id]
+    }
+
+    /// All nodes, indexed by id
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds an edge, and the nodes that are new; returns their ids
+    pub fn add_edge(&mut self, from: N, to: N, edge: E) -> (NodeId, NodeId) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push((to, edge));
+        (from, to)
+    }
+
+    /// Edges that leave the node, in the order they were added
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, E)] {
+        &self.edges[id]
+    }
+
+    pub fn neighbors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id].iter().map(|(to, _)| *to)
+    }
+
+    /// Removes the first edge from `from` to `to`, returning its value
+    pub fn remove_edge(&mut self, from: NodeId, to: NodeId) -> Option<E> {
+        let i = self.edges[from].iter().position(|(t, _)| *t == to)?;
+        Some(self.edges[from].remove(i).1)
+    }
+
+    /// Groups of connected nodes, ignoring the direction of the edges,
+    /// ordered by their smallest id
+    pub fn components(&self) -> Vec<Vec<NodeId>> {
+        fn root(parent: &mut [NodeId], mut id: NodeId) -> NodeId {
+            while parent[id] != id {
+                parent[id] = parent[parent[id]];
+                id = parent[id];
+            }
+            id
+        }
+        let mut parent = (0..self.len()).collect::<Vec<_>>();
+        for from in 0..self.len() {
+            for to in self.neighbors(from) {
+                let (a, b) = (root(&mut parent, from), root(&mut parent, to));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+        let mut groups = Vec::<Vec<NodeId>>::new();
+        let mut group_of = HashMap::<NodeId, usize>::new();
+        for id in 0..self.len() {
+            let group = *group_of.entry(root(&mut parent, id)).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[group].push(id);
+        }
+        groups
+    }
+
+    /// Node ids ordered so that all edges go forward, preferring
+    /// smaller ids; fails if the graph has a cycle
+    pub fn toposort(&self) -> Result<Vec<NodeId>> {
+        let mut indegree = vec![0; self.len()];
+        for from in 0..self.len() {
+            for to in self.neighbors(from) {
+                indegree[to] += 1;
+            }
+        }
+        let mut ready = (0..self.len())
+            .filter(|id| indegree[*id] == 0)
+            .map(Reverse)
+            .collect::<BinaryHeap<_>>();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse(id)) = ready.pop() {
+            order.push(id);
+            for to in self.neighbors(id) {
+                indegree[to] -= 1;
+                if indegree[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+        if order.len() < self.len() {
+            return Err(eyre!("graph has a cycle"));
+        }
+        Ok(order)
+    }
+}
+
+impl<N: Clone + Eq + Hash, E: Clone> Graph<N, E> {
+    /// Adds edges in both directions
+    pub fn add_undirected_edge(&mut self, a: N, b: N, edge: E) -> (NodeId, NodeId) {
+        let (a, b) = self.add_edge(a, b, edge.clone());
+        self.edges[b].push((a, edge));
+        (a, b)
+    }
+}
+
+/// Nodes reached by a [`bfs`], and how
+#[derive(Debug, Clone)]
+pub struct Reached<S> {
+    index: HashMap<S, usize>,
+    /// Node, index of its parent and distance, in the order reached
+    nodes: Vec<(S, usize, usize)>,
+}
+
+impl<S: Eq + Hash> Reached<S> {
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, node: &S) -> bool {
+        self.index.contains_key(node)
+    }
+
+    /// Nodes in the order they were reached, starting with the start
+    pub fn iter(&self) -> impl Iterator<Item = &S> {
+        self.nodes.iter().map(|(s, _, _)| s)
+    }
+
+    /// Number of edges from the start
+    pub fn distance(&self, node: &S) -> Option<usize> {
+        self.index.get(node).map(|&i| self.nodes[i].2)
+    }
+
+    /// Node that precedes `node` in a shortest path from the start
+    pub fn parent(&self, node: &S) -> Option<&S> {
+        let i = *self.index.get(node)?;
+        (i != 0).then(|| &self.nodes[self.nodes[i].1].0)
+    }
+
+    /// A shortest path, from the start to `node`
+    pub fn path(&self, node: &S) -> Option<Vec<&S>> {
+        let mut i = *self.index.get(node)?;
+        let mut path = vec![&self.nodes[i].0];
+        while i != 0 {
+            i = self.nodes[i].1;
+            path.push(&self.nodes[i].0);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `start`
+pub fn bfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Reached<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut reached = Reached {
+        index: HashMap::from([(start.clone(), 0)]),
+        nodes: vec![(start, 0, 0)],
+    };
+    let mut next = 0;
+    while next < reached.nodes.len() {
+        let distance = reached.nodes[next].2 + 1;
+        for s in successors(&reached.nodes[next].0) {
+            if let Entry::Vacant(e) = reached.index.entry(s) {
+                reached.nodes.push((e.key().clone(), next, distance));
+                e.insert(reached.nodes.len() - 1);
+            }
+        }
+        next += 1;
+    }
+    reached
+}
+
+/// Depth-first search from `start`; returns the nodes in the order
+/// they were visited
+pub fn dfs<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> Vec<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = HashSet::<S>::new();
+    let mut order = vec![];
+    let mut stack = vec![start];
+    while let Some(s) = stack.pop() {
+        if !visited.insert(s.clone()) {
+            continue;
+        }
+        let mut next = successors(&s)
+            .into_iter()
+            .filter(|n| !visited.contains(n))
+            .collect::<Vec<_>>();
+        // Visit the successors in the order they were given
+        next.reverse();
+        stack.extend(next);
+        order.push(s);
+    }
+    order
+}
+
+/// Cheapest path from `start` to a node that satisfies `is_goal`, as
+/// the list of nodes and its cost
+///
+/// `successors` gives the neighbors of a node with the cost to get
+/// there, and `heuristic` must never overestimate the cost to the
+/// goal, nor decrease by more than the cost of an edge.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut index = HashMap::from([(start.clone(), 0)]);
+    let mut frontier = BinaryHeap::from([(Reverse(heuristic(&start)), 0)]);
+    let mut states = vec![start];
+    // Cost and parent of each state:
+    let mut best = vec![(C::default(), 0)];
+    let mut done = vec![false];
+    while let Some((_, i)) = frontier.pop() {
+        if done[i] {
+            continue;
+        }
+        done[i] = true;
+        if is_goal(&states[i]) {
+            let mut path = vec![states[i].clone()];
+            let mut j = i;
+            while j != 0 {
+                j = best[j].1;
+                path.push(states[j].clone());
+            }
+            path.reverse();
+            return Some((path, best[i].0));
+        }
+        let cost = best[i].0;
+        for (next, step) in successors(&states[i]) {
+            let next_cost = cost + step;
+            let j = match index.entry(next) {
+                Entry::Occupied(e) => {
+                    let j = *e.get();
+                    if done[j] || next_cost >= best[j].0 {
+                        continue;
+                    }
+                    best[j] = (next_cost, i);
+                    j
+                }
+                Entry::Vacant(e) => {
+                    states.push(e.key().clone());
+                    best.push((next_cost, i));
+                    done.push(false);
+                    *e.insert(states.len() - 1)
+                }
+            };
+            frontier.push((Reverse(next_cost + heuristic(&states[j])), j));
+        }
+    }
+    None
+}
+
+/// Cheapest path from `start` to a node that satisfies `is_goal`; see
+/// [`astar`]
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+#[cfg(test)]
+fn example() -> Graph<&'static str, u32> {
+    // a -1-> b -1-> d
+    //  \           ^
+    //   5-> c -1--/     e
+    let mut g = Graph::new();
+    g.add_edge("a", "b", 1);
+    g.add_edge("a", "c", 5);
+    g.add_edge("b", "d", 1);
+    g.add_edge("c", "d", 1);
+    g.add_node("e");
+    g
+}
+
+#[test]
+fn test_graph() -> Result<()> {
+    let mut g = example();
+    assert_eq!(g.len(), 5);
+    let (a, d) = (g.id(&"a").unwrap(), g.id(&"d").unwrap());
+    assert_eq!(g.node(d), &"d");
+    assert_eq!(
+        g.neighbors(a).map(|n| *g.node(n)).collect::<Vec<_>>(),
+        ["b", "c"]
+    );
+    assert_eq!(g.add_node("a"), a);
+    assert_eq!(g.components(), vec![vec![0, 1, 2, 3], vec![4]]);
+    assert_eq!(g.toposort()?, vec![0, 1, 2, 3, 4]);
+    g.add_edge("d", "a", 1);
+    assert!(g.toposort().is_err());
+    assert_eq!(g.remove_edge(a, g.id(&"c").unwrap()), Some(5));
+    assert_eq!(g.remove_edge(a, d), None);
+    let mut u = Graph::<_>::new();
+    u.add_undirected_edge(1, 2, ());
+    u.add_undirected_edge(3, 4, ());
+    assert_eq!(u.components(), vec![vec![0, 1], vec![2, 3]]);
+    Ok(())
+}
+
+#[test]
+fn test_bfs_dfs() {
+    let g = example();
+    let reached = bfs(0, |&n| g.neighbors(n).collect::<Vec<_>>());
+    assert_eq!(reached.len(), 4);
+    assert!(!reached.contains(&4));
+    assert_eq!(reached.iter().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert_eq!(reached.distance(&3), Some(2));
+    assert_eq!(reached.parent(&3), Some(&1));
+    assert_eq!(reached.parent(&0), None);
+    assert_eq!(reached.path(&3), Some(vec![&0, &1, &3]));
+    assert_eq!(
+        dfs(0, |&n| g.neighbors(n).collect::<Vec<_>>()),
+        [0, 1, 3, 2]
+    );
+    // Implicit graph: numbers, going to n + 1 and 2 * n
+    let reached = bfs(1, |&n| [n + 1, 2 * n].into_iter().filter(|&m| m <= 20));
+    assert_eq!(reached.distance(&20), Some(5));
+}
+
+#[test]
+fn test_astar() {
+    let g = example();
+    let successors = |&n: &NodeId| g.edges(n).to_vec();
+    let (path, cost) = dijkstra(0, successors, |&n| n == 3).unwrap();
+    assert_eq!((path, cost), (vec![0, 1, 3], 2));
+    assert_eq!(dijkstra(0, successors, |&n| n == 4), None);
+    assert_eq!(dijkstra(0, successors, |&n| n == 0), Some((vec![0], 0)));
+    // Grid walk with manhattan distance as the heuristic; the wall at
+    // x = 5 has a gap at y = 9
+    let successors = |&(x, y): &(i32, i32)| {
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+            .into_iter()
+            .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+            .filter(|&(x, y)| x != 5 || y == 9)
+            .map(|p| (p, 1))
+    };
+    let goal = (9, 0);
+    let heuristic = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+    let (path, cost) = astar((0, 0), successors, heuristic, |p| *p == goal).unwrap();
+    assert_eq!(cost, 27);
+    assert_eq!(path.len(), 28);
+    assert!(path.contains(&(5, 9)));
+    let (_, cost) = dijkstra((0, 0), successors, |p| *p == goal).unwrap();
+    assert_eq!(cost, 27);
+}
+
+#[test]
+fn test_toposort() -> Result<()> {
+    let mut g = Graph::<_>::new();
+    for n in 0..3 {
+        g.add_node(n);
+    }
+    g.add_edge(0, 1, ());
+    // 1 is ready as soon as 0 is done, and goes before 2
+    assert_eq!(g.toposort()?, vec![0, 1, 2]);
+    g.add_edge(2, 0, ());
+    assert_eq!(g.toposort()?, vec![2, 0, 1]);
+    Ok(())
+}
@@ -0,0 +1,235 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Rectangular grid with dimensions defined at runtime
+//!
+//! Works like the `sqrid` grids, but the size comes from the input
+//! instead of being a type parameter.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use color_eyre::Result;
+
+use crate::Error;
+
+pub use sqrid::Dir;
+
+/// Position in a [`Grid`]; ordered top to bottom, left to right
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Pos {
+    pub const TOP_LEFT: Pos = Pos { x: 0, y: 0 };
+
+    pub fn new(x: usize, y: usize) -> Pos {
+        Pos { x, y }
+    }
+
+    pub fn tuple(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    pub fn manhattan(&self, other: &Pos) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Position in the given direction, unless it's negative
+    pub fn step(&self, dir: Dir) -> Option<Pos> {
+        let (dx, dy) = match dir {
+            Dir::N => (0, -1),
+            Dir::NE => (1, -1),
+            Dir::E => (1, 0),
+            Dir::SE => (1, 1),
+            Dir::S => (0, 1),
+            Dir::SW => (-1, 1),
+            Dir::W => (-1, 0),
+            Dir::NW => (-1, -1),
+        };
+        Some(Pos {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+impl Ord for Pos {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Pos {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<(usize, usize)> for Pos {
+    fn from((x, y): (usize, usize)) -> Pos {
+        Pos { x, y }
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Default + Clone> Grid<T> {
+    /// Grid of default cells; grids can't be empty
+    pub fn new(width: usize, height: usize) -> Result<Grid<T>, Error> {
+        if width == 0 || height == 0 {
+            let message = format!("empty grid of {}x{} cells", width, height);
+            return Err(Error::shape(message));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells: vec![T::default(); width * height],
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bottom_right(&self) -> Pos {
+        Pos::new(self.width - 1, self.height - 1)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y * self.width + pos.x])
+    }
+
+    /// Position in the given direction, if it's inside the grid
+    pub fn go(&self, pos: Pos, dir: Dir) -> Option<Pos> {
+        pos.step(dir).filter(|p| self.contains(*p))
+    }
+
+    /// All positions of the grid, top to bottom, left to right
+    pub fn iter_pos(&self) -> impl DoubleEndedIterator<Item = Pos> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Pos { x, y }))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.iter_pos().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{} out of the grid", pos);
+        &self.cells[pos.y * self.width + pos.x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{} out of the grid", pos);
+        &mut self.cells[pos.y * self.width + pos.x]
+    }
+}
+
+impl<T> Index<&Pos> for Grid<T> {
+    type Output = T;
+    fn index(&self, pos: &Pos) -> &T {
+        &self[*pos]
+    }
+}
+
+impl<T> IndexMut<&Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: &Pos) -> &mut T {
+        &mut self[*pos]
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = Error;
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if width == 0 {
+            return Err(Error::shape("empty grid"));
+        }
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            let message = format!("row {} has {} cells, expected {}", y, rows[y].len(), width);
+            return Err(Error::shape(message));
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+
+/// Writes one line per row; width and alignment apply to each cell
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                f.pad(&cell.to_string())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid() -> Result<()> {
+    let mut grid = Grid::try_from(vec![vec![1, 2, 3], vec![4, 5, 6]])?;
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Pos::new(2, 1)], 6);
+    assert_eq!(grid.get(Pos::new(3, 0)), None);
+    assert_eq!(grid.go(Pos::TOP_LEFT, Dir::SE), Some(Pos::new(1, 1)));
+    assert_eq!(grid.go(Pos::TOP_LEFT, Dir::W), None);
+    assert_eq!(grid.go(grid.bottom_right(), Dir::S), None);
+    grid[Pos::new(0, 1)] = 9;
+    assert_eq!(grid.to_string(), "123\n956\n");
+    assert_eq!(format!("{:>2}", grid), " 1 2 3\n 9 5 6\n");
+    assert_eq!(grid.iter_pos().last(), Some(grid.bottom_right()));
+    assert!(Grid::try_from(vec![vec![1, 2], vec![3]]).is_err());
+    assert_eq!(Grid::<u8>::new(2, 1)?.to_string(), "00\n");
+    assert!(Grid::<u8>::new(0, 2).is_err());
+    assert!(Grid::<u8>::new(2, 0).is_err());
+    assert!(Grid::<u8>::try_from(vec![]).is_err());
+    assert!(Grid::<u8>::try_from(vec![vec![]]).is_err());
+    Ok(())
+}
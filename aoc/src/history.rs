@@ -0,0 +1,365 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! History of the benchmarks, to catch performance regressions
+//!
+//! Benchmarks can append an [`Entry`] to the history file, with the
+//! git commit and the machine it ran on. The file is
+//! `bench-history.txt` in the current directory, or the one pointed to
+//! by `AOC_HISTORY`; benchmarks are only added to it with `--record`
+//! or when `AOC_HISTORY` is set. It has one entry per line in the format
+//! `<timestamp> <commit> <machine> <day> <part> <input hash> <runs>
+//! <parse ns> <solve ns>`, the times being medians. Benchmarks of a
+//! tree with changes have the commit followed by `-dirty`. [`compare`]
+//! matches the entries of two commits on the same machine and input.
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::solver::Part;
+
+/// Environment variable with the history file
+pub const ENV: &str = "AOC_HISTORY";
+
+pub fn history_path() -> PathBuf {
+    path_from(std::env::var_os(ENV))
+}
+
+fn path_from(env: Option<OsString>) -> PathBuf {
+    env.map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("bench-history.txt"))
+}
+
+/// History file where benchmarks go, if they are recorded
+pub fn destination(record: bool) -> Option<PathBuf> {
+    destination_from(record, std::env::var_os(ENV))
+}
+
+fn destination_from(record: bool, env: Option<OsString>) -> Option<PathBuf> {
+    (record || env.is_some()).then(|| path_from(env))
+}
+
+/// Output of a git command, if it succeeds
+fn git(args: &[&str]) -> Option<String> {
+    Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|output| output.trim().to_string())
+        .filter(|output| !output.is_empty())
+}
+
+/// Short hash of the commit `rev`, as in the history entries
+pub fn resolve(rev: &str) -> Option<String> {
+    let rev = format!("{}^{{commit}}", rev);
+    git(&["rev-parse", "--verify", "--quiet", "--short=12", &rev])
+}
+
+/// Short hash of the checked-out commit, with `-dirty` if there are
+/// changes; `unknown` outside of a git repository
+pub fn git_commit() -> String {
+    let Some(commit) = resolve("HEAD") else {
+        return "unknown".to_string();
+    };
+    // Fails if there are changes
+    let clean = Command::new("git")
+        .args(["diff", "--quiet", "HEAD"])
+        .status()
+        .is_ok_and(|status| status.success());
+    if clean {
+        commit
+    } else {
+        format!("{}-dirty", commit)
+    }
+}
+
+/// Identifier of this machine: `AOC_MACHINE`, the systemd machine id
+/// or the host name
+pub fn machine_id() -> String {
+    if let Ok(machine) = std::env::var("AOC_MACHINE") {
+        return machine;
+    }
+    ["/etc/machine-id", "/proc/sys/kernel/hostname"]
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|id| id.trim().chars().take(12).collect::<String>())
+        .find(|id| !id.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// A benchmark of a part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Seconds since the epoch
+    pub timestamp: u64,
+    pub commit: String,
+    pub machine: String,
+    pub day: u32,
+    pub part: Part,
+    pub input_hash: String,
+    pub runs: usize,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Entry {
+    /// Entry for a benchmark done now, on this machine and commit
+    pub fn new(
+        day: u32,
+        part: Part,
+        input_hash: &str,
+        runs: usize,
+        parse: Duration,
+        solve: Duration,
+    ) -> Entry {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        Entry {
+            timestamp,
+            commit: git_commit(),
+            machine: machine_id(),
+            day,
+            part,
+            input_hash: input_hash.to_string(),
+            runs,
+            parse,
+            solve,
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} {} {} {}",
+            self.timestamp,
+            self.commit,
+            self.machine,
+            self.day,
+            self.part,
+            self.input_hash,
+            self.runs,
+            self.parse.as_nanos(),
+            self.solve.as_nanos()
+        )
+    }
+}
+
+/// Entries of the history file contents, in the order they were added
+pub fn parse(contents: &str) -> Result<Vec<Entry>> {
+    let mut entries = vec![];
+    for (i, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields = line.split(' ').collect::<Vec<_>>();
+        let &[timestamp, commit, machine, day, part, hash, runs, parse, solve] = fields.as_slice()
+        else {
+            return Err(eyre!("history line {}: invalid entry {:?}", i + 1, line));
+        };
+        entries.push(Entry {
+            timestamp: timestamp.parse()?,
+            commit: commit.to_string(),
+            machine: machine.to_string(),
+            day: day.parse()?,
+            part: part.parse()?,
+            input_hash: hash.to_string(),
+            runs: runs.parse()?,
+            parse: Duration::from_nanos(parse.parse()?),
+            solve: Duration::from_nanos(solve.parse()?),
+        });
+    }
+    Ok(entries)
+}
+
+/// Loads the history file; a missing file has no entries.
+pub fn load(path: &Path) -> Result<Vec<Entry>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse(&contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(eyre!("{}: {}", path.display(), e)),
+    }
+}
+
+pub fn append(path: &Path, entry: &Entry) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| eyre!("{}: {}", path.display(), e))?;
+    writeln!(file, "{}", entry)?;
+    Ok(())
+}
+
+/// Median times of a part in two commits
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u32,
+    pub part: Part,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change of the time, in percent; positive is slower
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_nanos() as f64;
+        if baseline == 0.0 {
+            return 0.0;
+        }
+        100.0 * (self.current.as_nanos() as f64 - baseline) / baseline
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares the latest entries of the commit `baseline` with the
+/// latest ones of the commit `current`, or with the latest ones of any
+/// other commit; commits must be equal, so that the entries of a dirty
+/// tree are not taken for the ones of its commit. Only the entries of
+/// `machine` are used, and parts are matched by input
+pub fn compare(
+    entries: &[Entry],
+    machine: &str,
+    baseline: &str,
+    current: Option<&str>,
+) -> Vec<Comparison> {
+    let latest = |matches: &dyn Fn(&Entry) -> bool| {
+        let mut latest = HashMap::new();
+        for entry in entries
+            .iter()
+            .filter(|e| e.machine == machine && matches(e))
+        {
+            latest.insert((entry.day, entry.part, entry.input_hash.as_str()), entry);
+        }
+        latest
+    };
+    let baselines = latest(&|e| e.commit == baseline);
+    let currents = latest(&|e| match current {
+        Some(current) => e.commit == current,
+        None => e.commit != baseline,
+    });
+    let mut comparisons = currents
+        .iter()
+        .filter_map(|(key, current)| {
+            let baseline = baselines.get(key)?;
+            Some(Comparison {
+                day: current.day,
+                part: current.part,
+                baseline: baseline.total(),
+                current: current.total(),
+            })
+        })
+        .collect::<Vec<_>>();
+    comparisons.sort_by_key(|c| (c.day, c.part));
+    comparisons
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    let entry = Entry {
+        timestamp: 1700000000,
+        commit: "0a98727".to_string(),
+        machine: "m1".to_string(),
+        day: 17,
+        part: Part::B,
+        input_hash: "9a717fc46fd3e4a4".to_string(),
+        runs: 10,
+        parse: Duration::from_nanos(1500),
+        solve: Duration::from_millis(30),
+    };
+    let line = entry.to_string();
+    assert_eq!(
+        line,
+        "1700000000 0a98727 m1 17 b 9a717fc46fd3e4a4 10 1500 30000000"
+    );
+    assert_eq!(parse(&format!("# comment\n\n{}\n", line))?, vec![entry]);
+    assert!(parse("1700000000 0a98727 m1 17 b\n").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_destination() {
+    let env = || Some(OsString::from("/tmp/history.txt"));
+    assert_eq!(destination_from(false, None), None);
+    assert_eq!(
+        destination_from(true, None),
+        Some(PathBuf::from("bench-history.txt"))
+    );
+    assert_eq!(
+        destination_from(false, env()),
+        Some(PathBuf::from("/tmp/history.txt"))
+    );
+    assert_eq!(
+        destination_from(true, env()),
+        Some(PathBuf::from("/tmp/history.txt"))
+    );
+}
+
+#[test]
+fn test_compare() -> Result<()> {
+    let entries = parse(
+        "1 aaa m1 1 a h1 10 0 100
+         2 aaa m1 1 b h1 10 0 100
+         3 aaa m2 1 a h1 10 0 500
+         4 bbb m1 1 a h1 10 0 90
+         5 bbb m1 1 b h1 10 0 150
+         6 bbb m1 2 a h2 10 0 100
+         7 ccc m1 1 a h1 10 10 110",
+    )?;
+    let comparisons = compare(&entries, "m1", "aaa", Some("bbb"));
+    assert_eq!(comparisons.len(), 2);
+    assert_eq!(comparisons[0].change(), -10.0);
+    assert!(!comparisons[0].is_regression(10.0));
+    assert_eq!(comparisons[1].change(), 50.0);
+    assert!(comparisons[1].is_regression(10.0));
+    // The latest of any other commit
+    let comparisons = compare(&entries, "m1", "aaa", None);
+    assert_eq!(comparisons[0].current, Duration::from_nanos(120));
+    assert_eq!(comparisons[0].change(), 20.0);
+    assert!(compare(&entries, "m3", "aaa", None).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_compare_dirty() -> Result<()> {
+    let entries = parse(
+        "1 aaa m1 1 a h1 10 0 100
+         2 aaa-dirty m1 1 a h1 10 0 300
+         3 aaab m1 1 a h1 10 0 200",
+    )?;
+    // Neither the dirty tree nor the other commit are the baseline
+    let comparisons = compare(&entries, "m1", "aaa", Some("aaab"));
+    assert_eq!(comparisons[0].baseline, Duration::from_nanos(100));
+    assert_eq!(comparisons[0].change(), 100.0);
+    let comparisons = compare(&entries, "m1", "aaa", Some("aaa-dirty"));
+    assert_eq!(comparisons[0].change(), 200.0);
+    // The latest of any other commit is the dirty tree's
+    let comparisons = compare(&entries, "m1", "aaa-dirty", None);
+    assert_eq!(comparisons[0].baseline, Duration::from_nanos(300));
+    assert_eq!(comparisons[0].current, Duration::from_nanos(200));
+    assert!(compare(&entries, "m1", "aa", None).is_empty());
+    Ok(())
+}
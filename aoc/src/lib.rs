@@ -3,54 +3,49 @@
 // file 'LICENSE', which is part of this source code package.
 
 use std::fmt::{Debug, Display};
+use std::fs;
 pub use std::io::{stdin, BufRead};
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 pub use color_eyre::eyre::eyre;
 pub use color_eyre::Report;
 pub use color_eyre::Result;
+pub use log;
 
-#[macro_use]
-pub mod parser {
-    pub use color_eyre::eyre::eyre;
-    pub use color_eyre::Report;
-    pub use color_eyre::Result;
-    pub use combinator::all_consuming;
-    pub use nom::branch;
-    pub use nom::bytes::complete as bytes;
-    pub use nom::bytes::complete::tag;
-    pub use nom::character::complete as character;
-    pub use nom::character::complete::newline;
-    pub use nom::character::complete::satisfy;
-    pub use nom::combinator;
-    pub use nom::multi;
-    pub use nom::Finish;
-    pub use nom::IResult;
-    pub use std::io::BufRead;
-
-    #[macro_export]
-    macro_rules! parse_with {
-        ($parser:expr, $buf:ident) => {{
-            let mut input = String::default();
-            $buf.read_to_string(&mut input)?;
-            let result = all_consuming($parser)(&input).finish();
-            Ok(result.map_err(|e| eyre!("error reading input: {:?}", e))?.1)
-        }};
-    }
+pub mod solver;
+pub use solver::{Answer, DynSolver, Part, Solver, Timed};
 
-    pub fn space(input: &str) -> IResult<&str, &str> {
-        tag(" ")(input)
-    }
+pub mod alloc;
+pub mod anim;
+pub mod answers;
+pub use answers::{input_hash, Answers, Verdict};
 
-    pub fn lowercase_char(input: &str) -> IResult<&str, char> {
-        satisfy(|c| c.is_ascii_lowercase())(input)
-    }
+pub mod bench;
+pub mod cycle;
+pub mod error;
+pub use error::Error;
 
-    pub fn lowercase_str(input: &str) -> IResult<&str, String> {
-        let (input, cs) = multi::many1(lowercase_char)(input)?;
-        Ok((input, cs.into_iter().collect()))
-    }
-}
+pub mod examples;
+pub mod geom;
+pub mod graph;
+pub mod grid;
+pub mod history;
+pub mod logging;
+pub mod memo;
+pub mod options;
+pub use options::Options;
+
+pub mod output;
+pub use output::{Format, Record};
+
+#[macro_use]
+pub mod parser;
+pub mod prop;
+pub mod ranges;
+pub mod render;
+pub mod unparse;
 
 pub trait OptionExt<T> {
     fn ok_or_eyre<M>(self, message: M) -> Result<T>
@@ -74,10 +69,159 @@ pub fn elapsed(start: &Instant) -> String {
     format!("{}", humantime::Duration::from(start.elapsed()))
 }
 
-pub fn do_main<F: Fn() -> Result<T>, T: Display>(f: F) -> Result<()> {
+/// Conventional location of the input of a day
+pub fn default_input(day: u32) -> PathBuf {
+    Path::new("inputs").join(format!("day{:02}.txt", day))
+}
+
+/// Reads the input from the given file, `-` being stdin; without a
+/// file, reads stdin, unless it's a terminal, in which case the input
+/// comes from [`default_input`].
+pub fn read_input(day: u32, path: Option<&Path>) -> Result<String> {
+    let path = match path {
+        Some(path) if path == Path::new("-") => None,
+        Some(path) => Some(path.to_path_buf()),
+        None if stdin().is_terminal() => Some(default_input(day)),
+        None => None,
+    };
+    if let Some(path) = path {
+        fs::read_to_string(&path).map_err(|e| eyre!("{}: {}", path.display(), e))
+    } else {
+        let mut input = String::new();
+        stdin().lock().read_to_string(&mut input)?;
+        Ok(input)
+    }
+}
+
+pub fn do_main(solver: &dyn DynSolver, part: Part) -> Result<()> {
     color_eyre::install()?;
-    let start = Instant::now();
-    println!("{}", f()?);
-    println!("Elapsed: {}", elapsed(&start));
+    let options = Options::parse(std::env::args().skip(1))?;
+    logging::init(options.log.as_deref())?;
+    run_main(solver, part, &options)
+}
+
+/// Body of [`do_main`], for binaries that parse their own arguments
+pub fn run_main(solver: &dyn DynSolver, part: Part, options: &Options) -> Result<()> {
+    let history = history::destination(options.record);
+    run_with_history(solver, part, options, history.as_deref())
+}
+
+/// [`run_main`] adding the benchmarks to the `history` file
+fn run_with_history(
+    solver: &dyn DynSolver,
+    part: Part,
+    options: &Options,
+    history: Option<&Path>,
+) -> Result<()> {
+    if !solver.parts().contains(&part) {
+        return Err(Error::no_part(solver.day(), part).into());
+    }
+    let answers = Answers::load(&answers::answers_path())?;
+    let input = read_input(solver.day(), options.input.as_deref())?;
+    let hash = input_hash(&input);
+    let (answer, parse, solve, memory) = if let Some(runs) = options.bench {
+        let bench = bench::bench(solver, part, &input, runs)?;
+        let entry = history::Entry::new(
+            solver.day(),
+            part,
+            &hash,
+            runs,
+            bench.parse.median,
+            bench.solve.median,
+        );
+        if let Some(path) = history {
+            history::append(path, &entry)?;
+        }
+        (bench.answer, bench.parse, bench.solve, bench.memory)
+    } else {
+        let (timed, memory) = alloc::measure(|| solver.run_timed(part, &mut input.as_bytes()));
+        let timed = timed?;
+        let parse = bench::Stats::single(timed.parse);
+        let solve = bench::Stats::single(timed.solve);
+        (timed.answer, parse, solve, memory)
+    };
+    let verdict = answers.check(solver.day(), part, &hash, &answer.to_string());
+    let record = Record {
+        day: solver.day(),
+        part,
+        answer,
+        input_hash: hash,
+        verdict,
+        runs: options.bench,
+        parse,
+        solve,
+        memory,
+    };
+    println!("{}", record.render(options.format));
+    if record.verdict.is_fail() {
+        return Err(eyre!("{}: wrong answer", solver.name(part)));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bench_history() -> Result<()> {
+    struct Lines;
+    impl Solver for Lines {
+        const DAY: u32 = 0;
+        type Input = usize;
+        fn parse(&self, bufin: impl BufRead) -> Result<usize> {
+            Ok(bufin.lines().count())
+        }
+        fn unparse(&self, input: &usize) -> String {
+            "\n".repeat(*input)
+        }
+        fn part1(&self, input: usize) -> Result<Answer> {
+            Ok(input.into())
+        }
+        fn part2(&self, input: usize) -> Result<Answer> {
+            Ok(input.into())
+        }
+    }
+    let tmp = std::env::temp_dir();
+    let input = tmp.join(format!("aoc-bench-{}.txt", std::process::id()));
+    let history = tmp.join(format!("aoc-bench-history-{}.txt", std::process::id()));
+    fs::write(&input, "a\nb\n")?;
+    let options = Options {
+        bench: Some(2),
+        input: Some(input.clone()),
+        ..Options::default()
+    };
+    let run = |history: Option<&Path>| {
+        let _counters = alloc::COUNTERS.lock();
+        run_with_history(&Lines, Part::A, &options, history)
+    };
+    let result = run(None).and_then(|()| run(Some(&history)));
+    let entries = history::load(&history);
+    fs::remove_file(&input)?;
+    if history.exists() {
+        fs::remove_file(&history)?;
+    }
+    result?;
+    // Only the run with a history file is added to it
+    let entries = entries?;
+    assert_eq!(entries.len(), 1);
+    assert_eq!((entries[0].part, entries[0].runs), (Part::A, 2));
+    // Parts the solver doesn't have are rejected before reading the input
+    struct Single;
+    impl Solver for Single {
+        const DAY: u32 = 0;
+        const PARTS: &'static [Part] = &[Part::A];
+        type Input = ();
+        fn parse(&self, _bufin: impl BufRead) -> Result<()> {
+            Ok(())
+        }
+        fn unparse(&self, _input: &()) -> String {
+            String::new()
+        }
+        fn part1(&self, _input: ()) -> Result<Answer> {
+            Ok(0_usize.into())
+        }
+        fn part2(&self, _input: ()) -> Result<Answer> {
+            Err(Error::no_part(Self::DAY, Part::B).into())
+        }
+    }
+    let e = run_main(&Single, Part::B, &Options::default()).unwrap_err();
+    assert_eq!(e.downcast_ref(), Some(&Error::no_part(0, Part::B)));
     Ok(())
 }
@@ -0,0 +1,31 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Diagnostics on stderr
+//!
+//! Solvers log with the macros of the [`log`] crate, which `aoc`
+//! re-exports, e.g. `log::debug!(...)`. The targets are the module
+//! paths, so they start with the crate of the day. Nothing is logged
+//! by default; [`init`] takes a filter in the `env_logger` syntax, like
+//! `debug` or `day17=trace,aoc::memo=debug`, from the `--log` option
+//! or from `AOC_LOG`.
+
+use color_eyre::Result;
+
+/// Environment variable with the default filter
+pub const ENV: &str = "AOC_LOG";
+
+/// Installs the logger, with `filter` taking precedence over [`ENV`]
+pub fn init(filter: Option<&str>) -> Result<()> {
+    let mut builder = env_logger::Builder::new();
+    builder.filter_level(log::LevelFilter::Off);
+    builder.format_timestamp(None);
+    if let Some(filter) = filter {
+        builder.parse_filters(filter);
+    } else if let Ok(filter) = std::env::var(ENV) {
+        builder.parse_filters(&filter);
+    }
+    builder.try_init()?;
+    Ok(())
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Memoization with cache statistics
+//!
+//! [`Memo`] is a cache that counts its hits and misses. Recursive
+//! functions take it as an argument and wrap their body in
+//! [`Memo::get_or_insert_with`], which passes it on to the recursive
+//! calls. Each memo logs its [`Stats`] at debug level when dropped,
+//! with the `aoc::memo` target.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::mem;
+
+/// Usage counters of a [`Memo`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+    pub entries: usize,
+    /// Size of the table; doesn't include what the keys and values
+    /// own on the heap
+    pub bytes: usize,
+}
+
+impl Stats {
+    /// Fraction of the lookups that were hits
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} entries, {} bytes",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate(),
+            self.entries,
+            self.bytes
+        )
+    }
+}
+
+/// Cache of the values of a function, keyed by its arguments
+#[derive(Debug)]
+pub struct Memo<K, V> {
+    name: &'static str,
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Eq + Hash, V> Memo<K, V> {
+    /// Creates an empty memo; `name` identifies it in the statistics
+    pub fn new(name: &'static str) -> Self {
+        Memo {
+            name,
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Looks up `key`, counting a hit or a miss
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let value = self.cache.get(key);
+        if value.is_some() {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.cache.insert(key, value);
+    }
+
+    /// Value of `key`, calculated with `f` on a miss; `f` gets the memo
+    /// back for the recursive calls
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> V
+    where
+        V: Clone,
+        F: FnOnce(&mut Self) -> V,
+    {
+        if let Some(value) = self.get(&key) {
+            return value.clone();
+        }
+        let value = f(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+}
+
+impl<K, V> Memo<K, V> {
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.cache.len(),
+            bytes: self.cache.capacity() * mem::size_of::<(K, V)>(),
+        }
+    }
+}
+
+impl<K, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        log::debug!("{}: {}", self.name, self.stats());
+    }
+}
+
+#[test]
+fn test_memo() {
+    fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.get_or_insert_with(n, |memo| fib(memo, n - 1) + fib(memo, n - 2))
+    }
+    let mut memo = Memo::new("fib");
+    assert_eq!(fib(&mut memo, 90), 2880067194370816120);
+    let stats = memo.stats();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (87, 89, 89));
+    assert!(stats.bytes >= 89 * 16);
+    assert_eq!(fib(&mut memo, 90), 2880067194370816120);
+    assert_eq!(memo.stats().hits, 88);
+    assert_eq!(memo.get(&1), None);
+    assert_eq!(memo.stats().misses, 90);
+    memo.insert(1, 1);
+    assert_eq!(memo.get(&1), Some(&1));
+    assert_eq!(Stats::default().hit_rate(), 0.0);
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Command line options of the solver binaries
+
+use std::path::PathBuf;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::output::Format;
+
+pub const USAGE: &str =
+    "[--bench <runs>] [--record] [--format text|json|tsv] [--log <filter>] [input-file]";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Number of benchmark runs; `None` runs the solver once
+    pub bench: Option<usize>,
+    /// Whether to add the benchmark to the history, see
+    /// [`crate::history`]
+    pub record: bool,
+    pub format: Format,
+    /// Logging filter, see [`crate::logging`]
+    pub log: Option<String>,
+    /// Input file; `-` is stdin
+    pub input: Option<PathBuf>,
+}
+
+impl Options {
+    pub fn parse<I, S>(args: I) -> Result<Options>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut options = Options::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_ref() {
+                "--bench" => {
+                    let runs = args
+                        .next()
+                        .ok_or_else(|| eyre!("--bench requires the number of runs"))?;
+                    options.bench = Some(runs.as_ref().parse()?);
+                }
+                "--record" => options.record = true,
+                "--format" => {
+                    let format = args
+                        .next()
+                        .ok_or_else(|| eyre!("--format requires text, json or tsv"))?;
+                    options.format = format.as_ref().parse()?;
+                }
+                "--log" => {
+                    let filter = args
+                        .next()
+                        .ok_or_else(|| eyre!("--log requires a filter, e.g. debug"))?;
+                    options.log = Some(filter.as_ref().to_string());
+                }
+                path if !path.starts_with("--") && options.input.is_none() => {
+                    options.input = Some(PathBuf::from(path));
+                }
+                other => return Err(eyre!("invalid argument {}; usage: {}", other, USAGE)),
+            }
+        }
+        Ok(options)
+    }
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    assert_eq!(Options::parse::<_, &str>([])?, Options::default());
+    assert_eq!(Options::parse(["--bench", "10"])?.bench, Some(10));
+    assert!(Options::parse(["--bench"]).is_err());
+    assert!(Options::parse(["--bench", "x"]).is_err());
+    assert!(Options::parse(["--bench", "3", "--record"])?.record);
+    assert_eq!(Options::parse(["--format", "json"])?.format, Format::Json);
+    assert!(Options::parse(["--format", "xml"]).is_err());
+    assert_eq!(
+        Options::parse(["--log", "day17=debug"])?.log.as_deref(),
+        Some("day17=debug")
+    );
+    assert!(Options::parse(["--log"]).is_err());
+    assert!(Options::parse(["--foo"]).is_err());
+    let options = Options::parse(["input.txt", "--bench", "3"])?;
+    assert_eq!(options.input, Some(PathBuf::from("input.txt")));
+    assert_eq!(options.bench, Some(3));
+    assert!(Options::parse(["a.txt", "b.txt"]).is_err());
+    Ok(())
+}
@@ -0,0 +1,202 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Output of the result of a run, as text, JSON or TSV
+//!
+//! JSON is one object per line; TSV is one line with the columns
+//! day, part, answer, parse_ns, solve_ns, input_hash, check, runs,
+//! peak_bytes, allocations. In benchmark mode the times are the
+//! medians. The heap usage is only there when it's counted: JSON
+//! leaves out its fields, and TSV leaves its columns empty.
+
+use std::fmt::Write;
+use std::str::FromStr;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Report;
+use color_eyre::Result;
+
+use crate::alloc::Usage;
+use crate::answers::Verdict;
+use crate::bench::{duration, warmup, Stats};
+use crate::solver::{Answer, Part};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = Report;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            other => Err(eyre!("invalid format {}", other)),
+        }
+    }
+}
+
+/// The result of running a part on an input
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Answer,
+    pub input_hash: String,
+    pub verdict: Verdict,
+    /// Number of benchmark runs; `None` for a single run
+    pub runs: Option<usize>,
+    pub parse: Stats,
+    pub solve: Stats,
+    /// Heap usage, if it's being counted
+    pub memory: Option<Usage>,
+}
+
+fn json_str(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn check_str(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Pass => "PASS",
+        Verdict::Fail(_) => "FAIL",
+        Verdict::Unknown => "UNKNOWN",
+    }
+}
+
+impl Record {
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.text(),
+            Format::Json => self.json(),
+            Format::Tsv => self.tsv(),
+        }
+    }
+
+    fn text(&self) -> String {
+        let mut out = format!("{}\n", self.answer);
+        if let Some(runs) = self.runs {
+            out += &format!("Runs: {} (after {} warm-up)\n", runs, warmup(runs));
+            out += &format!("Parse: {}\n", self.parse);
+            out += &format!("Solve: {}\n", self.solve);
+        } else {
+            out += &format!("Elapsed: {}\n", duration(self.parse.min + self.solve.min));
+        }
+        if let Some(memory) = &self.memory {
+            out += &format!("Memory: {}\n", memory);
+        }
+        out += &format!("Check: {} (input {})", self.verdict, self.input_hash);
+        out
+    }
+
+    fn json(&self) -> String {
+        let answer = match &self.answer {
+            Answer::Int(i) => i.to_string(),
+            Answer::Str(s) => json_str(s),
+        };
+        let memory = match &self.memory {
+            Some(m) => format!(
+                ", \"peak_bytes\": {}, \"allocations\": {}",
+                m.peak, m.allocations
+            ),
+            None => String::new(),
+        };
+        format!(
+            "{{\"day\": {}, \"part\": \"{}\", \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"input_hash\": \"{}\", \"check\": \"{}\", \"runs\": {}{}}}",
+            self.day,
+            self.part,
+            answer,
+            self.parse.median.as_nanos(),
+            self.solve.median.as_nanos(),
+            self.input_hash,
+            check_str(&self.verdict),
+            self.runs.unwrap_or(1),
+            memory
+        )
+    }
+
+    fn tsv(&self) -> String {
+        let answer = self.answer.to_string().replace(['\t', '\n'], " ");
+        let (peak, allocations) = match &self.memory {
+            Some(m) => (m.peak.to_string(), m.allocations.to_string()),
+            None => (String::new(), String::new()),
+        };
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.day,
+            self.part,
+            answer,
+            self.parse.median.as_nanos(),
+            self.solve.median.as_nanos(),
+            self.input_hash,
+            check_str(&self.verdict),
+            self.runs.unwrap_or(1),
+            peak,
+            allocations
+        )
+    }
+}
+
+#[test]
+fn test_render() -> Result<()> {
+    use std::time::Duration;
+    let record = Record {
+        day: 8,
+        part: Part::B,
+        answer: Answer::from("a\"b"),
+        input_hash: "0123".to_string(),
+        verdict: Verdict::Unknown,
+        runs: None,
+        parse: Stats::single(Duration::from_nanos(10)),
+        solve: Stats::single(Duration::from_nanos(20)),
+        memory: None,
+    };
+    assert_eq!(
+        record.render(Format::Json),
+        "{\"day\": 8, \"part\": \"b\", \"answer\": \"a\\\"b\", \"parse_ns\": 10, \"solve_ns\": 20, \"input_hash\": \"0123\", \"check\": \"UNKNOWN\", \"runs\": 1}"
+    );
+    assert_eq!(
+        record.render(Format::Tsv),
+        "8\tb\ta\"b\t10\t20\t0123\tUNKNOWN\t1\t\t"
+    );
+    assert_eq!(
+        record.render(Format::Text),
+        "a\"b\nElapsed: 30ns\nCheck: UNKNOWN (input 0123)"
+    );
+    let record = Record {
+        memory: Some(Usage {
+            allocations: 5,
+            allocated: 4000,
+            peak: 3000,
+        }),
+        ..record
+    };
+    assert!(record
+        .render(Format::Json)
+        .ends_with("\"runs\": 1, \"peak_bytes\": 3000, \"allocations\": 5}"));
+    assert_eq!(
+        record.render(Format::Tsv),
+        "8\tb\ta\"b\t10\t20\t0123\tUNKNOWN\t1\t3000\t5"
+    );
+    assert!(record
+        .render(Format::Text)
+        .contains("\nMemory: peak 2.9 KiB, 5 allocations (3.9 KiB)\n"));
+    Ok(())
+}
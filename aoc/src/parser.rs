@@ -0,0 +1,372 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Parsing support, based on nom
+//!
+//! The parsers use [`Error`] as the nom error type, which keeps the
+//! furthest failure among alternatives. Parsers can be wrapped in
+//! [`context`] to name them in the error messages.
+
+use std::fmt;
+use std::fmt::Display;
+
+pub use color_eyre::eyre::eyre;
+pub use color_eyre::Report;
+pub use color_eyre::Result;
+pub use combinator::all_consuming;
+pub use nom::branch;
+pub use nom::bytes::complete as bytes;
+pub use nom::bytes::complete::tag;
+pub use nom::character::complete as character;
+pub use nom::character::complete::newline;
+pub use nom::character::complete::satisfy;
+pub use nom::combinator;
+pub use nom::error::context;
+pub use nom::multi;
+pub use nom::Finish;
+pub use std::io::BufRead;
+
+use nom::error::{ContextError, ErrorKind, ParseError};
+
+use crate::grid::Pos;
+
+pub type IResult<I, O, E = Error<I>> = nom::IResult<I, O, E>;
+
+#[macro_export]
+macro_rules! parse_with {
+    ($parser:expr, $buf:ident) => {{
+        let mut input = String::default();
+        $buf.read_to_string(&mut input)?;
+        $crate::parser::parse_str($parser, &input)
+    }};
+}
+
+/// Fails at `input` with the given message, without backtracking
+pub fn fail<O>(input: &str, message: String) -> IResult<&str, O> {
+    let mut e = Error::from_error_kind(input, ErrorKind::Verify);
+    e.message = Some(message);
+    Err(nom::Err::Failure(e))
+}
+
+/// The nom error type of our parsers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<I> {
+    pub input: I,
+    pub kind: ErrorKind,
+    /// Character that was expected at `input`
+    pub expected: Option<char>,
+    /// Description of the error from [`fail`]
+    pub message: Option<String>,
+    /// Names given with [`context`], innermost first
+    pub context: Vec<&'static str>,
+}
+
+impl<'a> ParseError<&'a str> for Error<&'a str> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Error {
+            input,
+            kind,
+            expected: None,
+            message: None,
+            context: vec![],
+        }
+    }
+
+    fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        let mut e = Error::from_error_kind(input, ErrorKind::Char);
+        e.expected = Some(c);
+        e
+    }
+
+    /// The alternative that got further in the input
+    fn or(self, other: Self) -> Self {
+        if other.input.len() < self.input.len() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<&'a str> {
+    fn add_context(_input: &'a str, context: &'static str, mut other: Self) -> Self {
+        other.context.push(context);
+        other
+    }
+}
+
+/// A parse error, pointing at the line and column where it happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputError {
+    pub line: usize,
+    pub column: usize,
+    pub line_text: String,
+    pub message: String,
+    /// Names of the parsers that failed, innermost first
+    pub context: Vec<&'static str>,
+}
+
+fn describe(kind: ErrorKind, expected: Option<char>) -> String {
+    match (kind, expected) {
+        (_, Some('\n')) => "expected end of line".to_string(),
+        (_, Some(c)) => format!("expected {:?}", c),
+        (ErrorKind::Eof, _) => "unexpected trailing input".to_string(),
+        (ErrorKind::Tag, _) => "unexpected text".to_string(),
+        (ErrorKind::Digit, _) => "expected a number".to_string(),
+        (ErrorKind::OneOf | ErrorKind::NoneOf | ErrorKind::Satisfy, _) => {
+            "unexpected character".to_string()
+        }
+        (kind, _) => format!("failed {}", kind.description()),
+    }
+}
+
+impl InputError {
+    /// Error at the given line and column, both starting at 1, of an
+    /// input that's not parsed with nom
+    pub fn at(line: usize, column: usize, line_text: &str, message: impl Into<String>) -> Self {
+        InputError {
+            line,
+            column,
+            line_text: line_text.to_string(),
+            message: message.into(),
+            context: vec![],
+        }
+    }
+
+    fn new(input: &str, e: Error<&str>) -> InputError {
+        let remaining = e.input.len();
+        let offset = input.len() - remaining;
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        InputError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            line_text: input[line_start..line_end].to_string(),
+            message: match e.message {
+                Some(message) => message,
+                None if remaining == 0 => "unexpected end of input".to_string(),
+                None => describe(e.kind, e.expected),
+            },
+            context: e.context,
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parse error at line {}, column {}",
+            self.line, self.column
+        )?;
+        if let Some(name) = self.context.first() {
+            write!(f, " in {}", name)?;
+        }
+        writeln!(f, ": {}", self.message)?;
+        let lineno = self.line.to_string();
+        let pad = " ".repeat(lineno.len());
+        writeln!(f, "{} |", pad)?;
+        writeln!(f, "{} | {}", lineno, self.line_text)?;
+        write!(f, "{} | {}^", pad, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Parses the whole input, returning an [`InputError`] on failure, as
+/// [`crate::Error::Parse`]
+pub fn parse_str<'a, O, P>(parser: P, input: &'a str) -> Result<O>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    match all_consuming(parser)(input).finish() {
+        Ok((_, output)) => Ok(output),
+        Err(e) => Err(crate::Error::Parse(InputError::new(input, e)).into()),
+    }
+}
+
+pub fn space(input: &str) -> IResult<&str, &str> {
+    tag(" ")(input)
+}
+
+pub fn lowercase_char(input: &str) -> IResult<&str, char> {
+    satisfy(|c| c.is_ascii_lowercase())(input)
+}
+
+pub fn lowercase_str(input: &str) -> IResult<&str, String> {
+    let (input, cs) = multi::many1(lowercase_char)(input)?;
+    Ok((input, cs.into_iter().collect()))
+}
+
+fn char_grid_cell<T>(input: &str) -> IResult<&str, T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    let (rest, c) = character::none_of("\n")(input)?;
+    match T::try_from(c) {
+        Ok(cell) => Ok((rest, cell)),
+        Err(e) => fail(input, e.to_string()),
+    }
+}
+
+/// Parses a grid of characters, one row per line, converting each
+/// character with `TryFrom<char>`
+///
+/// The grid ends at an empty line or at the end of the input, and all
+/// rows must have the same length.
+pub fn char_grid<T>(input: &str) -> IResult<&str, Vec<Vec<T>>>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    context("grid", char_grid_rows)(input)
+}
+
+fn char_grid_rows<T>(input: &str) -> IResult<&str, Vec<Vec<T>>>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    let mut rows: Vec<Vec<T>> = vec![];
+    let mut input = input;
+    loop {
+        let row_input = input;
+        let (rest, row) = match multi::many1(char_grid_cell)(input) {
+            Err(nom::Err::Error(_)) if !rows.is_empty() => break,
+            result => result?,
+        };
+        if let Some(width) = rows.first().map(|r| r.len()) {
+            if row.len() != width {
+                let (offset, _) = row_input
+                    .char_indices()
+                    .nth(width.min(row.len()))
+                    .unwrap_or((row_input.len(), '\n'));
+                return fail(
+                    &row_input[offset..],
+                    format!("row has {} cells, expected {}", row.len(), width),
+                );
+            }
+        }
+        let (rest, _) = newline(rest)?;
+        rows.push(row);
+        input = rest;
+    }
+    Ok((input, rows))
+}
+
+/// Rows of a grid and the positions of its marker cells
+pub type Marked<T> = (Vec<Vec<T>>, Vec<Pos>);
+
+/// Like [`char_grid`], also returning the positions of the `marker`
+/// cells
+pub fn char_grid_marked<T>(marker: T) -> impl FnMut(&str) -> IResult<&str, Marked<T>>
+where
+    T: TryFrom<char> + PartialEq,
+    T::Error: Display,
+{
+    move |input| {
+        let (input, rows) = char_grid(input)?;
+        let marked = rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, cell)| **cell == marker)
+                    .map(move |(x, _)| Pos::new(x, y))
+            })
+            .collect();
+        Ok((input, (rows, marked)))
+    }
+}
+
+#[cfg(test)]
+fn input_error(report: Report) -> Result<InputError> {
+    match report.downcast::<crate::Error>()? {
+        crate::Error::Parse(e) => Ok(e),
+        other => Err(eyre!("not a parse error: {}", other)),
+    }
+}
+
+#[test]
+fn test_char_grid() -> Result<()> {
+    #[derive(Debug, PartialEq)]
+    struct Bit(bool);
+    impl TryFrom<char> for Bit {
+        type Error = crate::Error;
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '0' => Ok(Bit(false)),
+                '1' => Ok(Bit(true)),
+                other => Err(crate::Error::invalid_char("bit", other)),
+            }
+        }
+    }
+    let rows = parse_str(char_grid::<Bit>, "01\n10\n")?;
+    assert_eq!(
+        rows,
+        vec![vec![Bit(false), Bit(true)], vec![Bit(true), Bit(false)]]
+    );
+    let (_, marked) = parse_str(char_grid_marked(Bit(true)), "01\n11\n")?;
+    assert_eq!(marked, vec![Pos::new(1, 0), Pos::new(0, 1), Pos::new(1, 1)]);
+    let e = parse_str(char_grid::<Bit>, "01\n12\n").unwrap_err();
+    let e = input_error(e)?;
+    assert_eq!((e.line, e.column), (2, 2));
+    assert_eq!(e.message, "invalid bit 2");
+    let e = parse_str(char_grid::<Bit>, "01\n1\n").unwrap_err();
+    let e = input_error(e)?;
+    assert_eq!((e.line, e.column), (2, 2));
+    assert_eq!(e.message, "row has 1 cells, expected 2");
+    let e = parse_str(char_grid::<Bit>, "01\n101\n").unwrap_err();
+    let e = input_error(e)?;
+    assert_eq!((e.line, e.column), (2, 3));
+    Ok(())
+}
+
+#[test]
+fn test_input_error() -> Result<()> {
+    fn line(input: &str) -> IResult<&str, u32> {
+        let (input, n) = character::u32(input)?;
+        let (input, _) = combinator::cut(newline)(input)?;
+        Ok((input, n))
+    }
+    fn number(input: &str) -> IResult<&str, u32> {
+        context("number", line)(input)
+    }
+    assert_eq!(parse_str(multi::many1(number), "1\n2\n")?, vec![1, 2]);
+    let e = parse_str(multi::many1(number), "1\n22\n3x\n4\n").unwrap_err();
+    let e = input_error(e)?;
+    assert_eq!((e.line, e.column), (3, 2));
+    assert_eq!(e.line_text, "3x");
+    assert_eq!(e.context, vec!["number"]);
+    assert_eq!(
+        e.to_string(),
+        "parse error at line 3, column 2 in number: expected end of line\n  |\n3 | 3x\n  |  ^"
+    );
+    let e = parse_str(multi::many1(number), "1\n2").unwrap_err();
+    let e = input_error(e)?;
+    assert_eq!((e.line, e.column), (2, 2));
+    assert_eq!(e.message, "unexpected end of input");
+    // Without cut, the lines just stop
+    let e = parse_str(multi::many1(character::u32), "1x").unwrap_err();
+    let e = input_error(e)?;
+    assert_eq!(
+        (e.column, e.message.as_str()),
+        (2, "unexpected trailing input")
+    );
+    // The alternative that got further is reported
+    let ab = combinator::recognize(nom::sequence::pair(tag("a"), tag("b")));
+    let e = parse_str(branch::alt((ab, tag("x"))), "ac").unwrap_err();
+    let e = input_error(e)?;
+    assert_eq!((e.column, e.message.as_str()), (2, "unexpected text"));
+    Ok(())
+}
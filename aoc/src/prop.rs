@@ -0,0 +1,309 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Property tests of solvers with random inputs
+//!
+//! Days implement [`Generate`] to build random valid input models,
+//! and use the [`properties!`](crate::properties!) macro to define a
+//! test that checks, for each generated input, that:
+//! - the parser accepts its [`unparse`](Solver::unparse)d text and
+//!   gives back the same model;
+//! - no part of the solver panics on it (errors are fine), except
+//!   the `untested` ones, that only work on the real input.
+//!
+//! Cases are deterministic, with inputs that grow with the case
+//! number. `AOC_PROP_SEED` and `AOC_PROP_CASES` change the seed and
+//! the number of cases. Failures are retried with smaller sizes, and
+//! the smallest failing input is reported.
+
+use std::env;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::solver::{DynSolver, Part, Solver};
+
+pub const SEED: u64 = 2023;
+pub const CASES: usize = 64;
+/// Size of the last case
+pub const MAX_SIZE: usize = 20;
+
+/// A day that can generate random valid inputs
+pub trait Generate: Solver {
+    /// Random input; its dimensions should grow with `g.size()`
+    fn generate(&self, g: &mut Gen) -> Self::Input;
+}
+
+/// Integers that [`Gen::range`] can pick
+pub trait Int: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(i: i128) -> Self;
+}
+
+macro_rules! int_impl {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+                fn from_i128(i: i128) -> Self {
+                    i as $t
+                }
+            }
+        )*
+    };
+}
+
+int_impl!(i8, i16, i32, i64, u8, u16, u32, u64, usize);
+
+/// Source of random values, with a size hint for the inputs
+#[derive(Debug, Clone)]
+pub struct Gen {
+    state: u64,
+    size: usize,
+}
+
+impl Gen {
+    pub fn new(seed: u64, size: usize) -> Gen {
+        Gen { state: seed, size }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// splitmix64
+    pub fn u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn range<T: Int>(&mut self, range: RangeInclusive<T>) -> T {
+        let (lo, hi) = (range.start().to_i128(), range.end().to_i128());
+        assert!(lo <= hi, "empty range");
+        let span = (hi - lo + 1) as u128;
+        T::from_i128(lo + (self.u64() as u128 % span) as i128)
+    }
+
+    /// A length from `min` to `min + size`
+    pub fn len(&mut self, min: usize) -> usize {
+        self.range(min..=min + self.size)
+    }
+
+    pub fn bool(&mut self) -> bool {
+        self.u64() & 1 == 1
+    }
+
+    /// True with probability `1/n`
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.u64().is_multiple_of(n)
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn vec<T>(&mut self, len: usize, mut f: impl FnMut(&mut Gen) -> T) -> Vec<T> {
+        (0..len).map(|_| f(self)).collect()
+    }
+
+    /// `height` rows of `width` cells
+    pub fn grid<T>(
+        &mut self,
+        width: usize,
+        height: usize,
+        mut f: impl FnMut(&mut Gen) -> T,
+    ) -> Vec<Vec<T>> {
+        let mut rows = Vec::with_capacity(height);
+        for _ in 0..height {
+            rows.push(self.vec(width, &mut f));
+        }
+        rows
+    }
+
+    /// One of the characters of `chars`
+    pub fn char(&mut self, chars: &str) -> char {
+        let i = self.range(0..=chars.chars().count() - 1);
+        chars.chars().nth(i).unwrap()
+    }
+
+    /// A string of `len` characters picked from `chars`
+    pub fn string(&mut self, len: usize, chars: &str) -> String {
+        (0..len).map(|_| self.char(chars)).collect()
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+fn env_or<T: std::str::FromStr>(name: &str, default: T) -> Result<T> {
+    match env::var(name) {
+        Ok(value) => value
+            .parse()
+            .map_err(|_| eyre!("invalid {}: {}", name, value)),
+        Err(_) => Ok(default),
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "panic".to_string()
+    }
+}
+
+/// Checks the properties on a single generated input
+pub fn check_case<S>(solver: &S, untested: &[Part], seed: u64, size: usize) -> Result<()>
+where
+    S: Generate,
+    S::Input: PartialEq + Debug,
+{
+    let input = solver.generate(&mut Gen::new(seed, size));
+    let text = solver.unparse(&input);
+    let fail = |msg: String| eyre!("{}\ninput:\n{}", msg, text);
+    let parsed = solver
+        .parse(text.as_bytes())
+        .map_err(|e| fail(format!("parser rejected the input: {}", e)))?;
+    if parsed != input {
+        return Err(fail(format!(
+            "parse(unparse(x)) != x:\n{:?}\n{:?}",
+            input, parsed
+        )));
+    }
+    for part in S::PARTS.iter().copied().filter(|p| !untested.contains(p)) {
+        let run = panic::catch_unwind(AssertUnwindSafe(|| solver.run(part, &mut text.as_bytes())));
+        if let Err(payload) = run {
+            return Err(fail(format!(
+                "{} panicked: {}",
+                solver.name(part),
+                panic_message(payload.as_ref())
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Checks the properties on `AOC_PROP_CASES` generated inputs
+pub fn check<S>(solver: &S, untested: &[Part]) -> Result<()>
+where
+    S: Generate,
+    S::Input: PartialEq + Debug,
+{
+    let seed = env_or("AOC_PROP_SEED", SEED)?;
+    let cases = env_or("AOC_PROP_CASES", CASES)?;
+    let mut seeds = Gen::new(seed ^ S::DAY as u64, 0);
+    for case in 0..cases {
+        let case_seed = seeds.u64();
+        let size = 1 + case * MAX_SIZE / cases.max(1);
+        if check_case(solver, untested, case_seed, size).is_ok() {
+            continue;
+        }
+        // Report the smallest size that still fails with this seed
+        for size in 1..=size {
+            if let Err(e) = check_case(solver, untested, case_seed, size) {
+                return Err(eyre!(
+                    "case {} (AOC_PROP_SEED={}, size {}): {}",
+                    case,
+                    seed,
+                    size,
+                    e
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Defines the property test of a day, see [`prop`](crate::prop)
+///
+/// The solver can be given parameters that keep generated inputs
+/// tractable, and parts that only work on the real input can be left
+/// out, as in `aoc::properties!(Day20, untested: [B]);`.
+///
+/// ```ignore
+/// aoc::properties!(Day11);
+/// aoc::properties!(Day21, Day21 { size: 11, steps1: 6, steps2: 20 });
+/// ```
+#[macro_export]
+macro_rules! properties {
+    ($solver:ty $(, untested: [$($untested:ident),*])?) => {
+        #[test]
+        fn test_properties() -> $crate::Result<()> {
+            $crate::prop::check(
+                &<$solver>::default(),
+                &[$($($crate::Part::$untested),*)?],
+            )
+        }
+    };
+    ($solver:ty, $params:expr $(, untested: [$($untested:ident),*])?) => {
+        #[test]
+        fn test_properties() -> $crate::Result<()> {
+            $crate::prop::check(&$params, &[$($($crate::Part::$untested),*)?])
+        }
+    };
+}
+
+#[test]
+fn test_gen() {
+    let mut g = Gen::new(1, 5);
+    for _ in 0..100 {
+        assert!((3..=5).contains(&g.range(3..=5)));
+        assert!((-2..=2).contains(&g.range(-2_i64..=2)));
+        assert!((1..=6).contains(&g.len(1)));
+    }
+    let mut v = (0..10).collect::<Vec<_>>();
+    g.shuffle(&mut v);
+    v.sort();
+    assert_eq!(v, (0..10).collect::<Vec<_>>());
+    assert_eq!(Gen::new(7, 1).u64(), Gen::new(7, 1).u64());
+    let grid = g.grid(3, 2, |g| g.bool());
+    assert_eq!((grid.len(), grid[0].len()), (2, 3));
+    assert!(g.string(4, "ab").chars().all(|c| c == 'a' || c == 'b'));
+}
+
+#[test]
+fn test_check() {
+    use crate::solver::Answer;
+    #[derive(Default)]
+    struct Div;
+    impl Solver for Div {
+        const DAY: u32 = 0;
+        type Input = Vec<u32>;
+        fn parse(&self, bufin: impl std::io::BufRead) -> Result<Vec<u32>> {
+            bufin.lines().map(|l| Ok(l?.parse()?)).collect()
+        }
+        fn unparse(&self, input: &Vec<u32>) -> String {
+            crate::unparse::lines(input)
+        }
+        fn part1(&self, input: Vec<u32>) -> Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+        fn part2(&self, input: Vec<u32>) -> Result<Answer> {
+            Ok(input.iter().map(|i| 100 / i).sum::<u32>().into())
+        }
+    }
+    impl Generate for Div {
+        fn generate(&self, g: &mut Gen) -> Vec<u32> {
+            let len = g.len(1);
+            g.vec(len, |g| g.range(0..=9))
+        }
+    }
+    assert!(check(&Div, &[Part::B]).is_ok());
+    let err = check(&Div, &[]).unwrap_err().to_string();
+    assert!(err.contains("day00b panicked"), "{}", err);
+    assert!(err.contains("divide by zero"), "{}", err);
+}
@@ -0,0 +1,609 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Integer intervals, sets of intervals, and boxes of them
+//!
+//! [`IntervalSet`] is kept normalized: sorted, disjoint and without
+//! adjacent intervals, so that equal sets compare equal.
+//! [`CuboidSet`] keeps its boxes disjoint, but doesn't merge them.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Closed interval `start..=end`; empty if `start > end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const EMPTY: Interval = Interval::new(0, -1);
+
+    pub const fn new(start: i64, end: i64) -> Interval {
+        Interval { start, end }
+    }
+
+    /// Interval between two values, in any order
+    pub fn spanning(a: i64, b: i64) -> Interval {
+        Interval::new(a.min(b), a.max(b))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    /// Number of values in the interval; saturates at `u64::MAX`, one
+    /// short of all the `i64` values
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start).saturating_add(1)
+        }
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let i = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!i.is_empty()).then_some(i)
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+
+    /// The values below `at` and the others; either can be empty
+    pub fn split(&self, at: i64) -> (Interval, Interval) {
+        let below = match at.checked_sub(1) {
+            Some(last) => Interval::new(self.start, self.end.min(last)),
+            None => Interval::EMPTY,
+        };
+        (below, Interval::new(self.start.max(at), self.end))
+    }
+
+    /// The values up to `at`, included, and the others; either can be
+    /// empty
+    pub fn split_after(&self, at: i64) -> (Interval, Interval) {
+        let above = match at.checked_add(1) {
+            Some(first) => Interval::new(self.start.max(first), self.end),
+            None => Interval::EMPTY,
+        };
+        (Interval::new(self.start, self.end.min(at)), above)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// Set of integers stored as intervals
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet(Vec<Interval>);
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    fn normalized(mut intervals: Vec<Interval>) -> IntervalSet {
+        intervals.retain(|i| !i.is_empty());
+        intervals.sort_by_key(|i| i.start);
+        let mut merged = Vec::<Interval>::with_capacity(intervals.len());
+        for i in intervals {
+            match merged.last_mut() {
+                Some(last) if i.start <= last.end.saturating_add(1) => {
+                    last.end = last.end.max(i.end);
+                }
+                _ => merged.push(i),
+            }
+        }
+        IntervalSet(merged)
+    }
+
+    /// The intervals, sorted and separated by at least one value
+    pub fn intervals(&self) -> &[Interval] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval> {
+        self.0.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Number of values in the set; saturates like [`Interval::len`]
+    pub fn len(&self) -> u64 {
+        self.0
+            .iter()
+            .map(Interval::len)
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.0.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.0.last().map(|i| i.end)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.0.partition_point(|i| i.end < value);
+        self.0.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let mut intervals = std::mem::take(&mut self.0);
+        intervals.push(interval);
+        *self = IntervalSet::normalized(intervals);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        IntervalSet::normalized(self.0.iter().chain(other.0.iter()).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut i, mut j) = (0, 0);
+        let mut result = vec![];
+        while i < self.0.len() && j < other.0.len() {
+            result.extend(self.0[i].intersection(&other.0[j]));
+            if self.0[i].end < other.0[j].end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet(result)
+    }
+
+    pub fn subtract(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = vec![];
+        for interval in &self.0 {
+            let mut rest = Some(*interval);
+            for o in &other.0 {
+                let Some(r) = rest else { break };
+                if o.end < r.start {
+                    continue;
+                }
+                if o.start > r.end {
+                    break;
+                }
+                if o.start > r.start {
+                    result.push(Interval::new(r.start, o.start - 1));
+                }
+                rest = (o.end < r.end).then(|| Interval::new(o.end + 1, r.end));
+            }
+            result.extend(rest);
+        }
+        IntervalSet(result)
+    }
+
+    /// All values moved by `offset`
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        IntervalSet(self.0.iter().map(|i| i.shift(offset)).collect())
+    }
+
+    /// The values moved by the offset of the first interval of `map`
+    /// that contains them; values outside of all of them are kept
+    pub fn map(&self, map: &[(Interval, i64)]) -> IntervalSet {
+        let mut mapped = vec![];
+        let mut left = self.clone();
+        for (src, offset) in map {
+            let matching = left.intersection(&IntervalSet::from(*src));
+            left = left.subtract(&matching);
+            mapped.extend(matching.0.iter().map(|i| i.shift(*offset)));
+        }
+        mapped.extend(left.0);
+        IntervalSet::normalized(mapped)
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> IntervalSet {
+        IntervalSet::normalized(vec![interval])
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> IntervalSet {
+        IntervalSet::normalized(iter.into_iter().collect())
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", crate::unparse::join(&self.0, ", "))
+    }
+}
+
+/// Axis-aligned box with one interval per dimension; empty if any of
+/// them is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cuboid<const N: usize>(pub [Interval; N]);
+
+impl<const N: usize> Cuboid<N> {
+    pub const fn new(intervals: [Interval; N]) -> Cuboid<N> {
+        Cuboid(intervals)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(Interval::is_empty)
+    }
+
+    /// Number of points in the box; saturates at `u64::MAX`
+    pub fn volume(&self) -> u64 {
+        self.0
+            .iter()
+            .map(Interval::len)
+            .fold(1, u64::saturating_mul)
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.0.iter().zip(point).all(|(i, v)| i.contains(v))
+    }
+
+    pub fn intersection(&self, other: &Cuboid<N>) -> Option<Cuboid<N>> {
+        let mut result = *self;
+        for (i, o) in result.0.iter_mut().zip(other.0.iter()) {
+            *i = i.intersection(o)?;
+        }
+        Some(result)
+    }
+
+    /// The points below `at` in the given axis and the others; either
+    /// can be empty
+    pub fn split(&self, axis: usize, at: i64) -> (Cuboid<N>, Cuboid<N>) {
+        let (mut below, mut above) = (*self, *self);
+        (below[axis], above[axis]) = self[axis].split(at);
+        (below, above)
+    }
+
+    /// Like [`Cuboid::split`], with `at` in the first box
+    pub fn split_after(&self, axis: usize, at: i64) -> (Cuboid<N>, Cuboid<N>) {
+        let (mut below, mut above) = (*self, *self);
+        (below[axis], above[axis]) = self[axis].split_after(at);
+        (below, above)
+    }
+
+    /// Disjoint boxes with the points that are not in `other`
+    pub fn subtract(&self, other: &Cuboid<N>) -> Vec<Cuboid<N>> {
+        if self.intersection(other).is_none() {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        }
+        // Cut the slabs outside of other, one axis at a time
+        let mut pieces = vec![];
+        let mut rest = *self;
+        for axis in 0..N {
+            let (below, middle) = rest.split(axis, other[axis].start);
+            let (middle, above) = middle.split_after(axis, other[axis].end);
+            pieces.extend([below, above].into_iter().filter(|c| !c.is_empty()));
+            rest = middle;
+        }
+        pieces
+    }
+}
+
+impl<const N: usize> Index<usize> for Cuboid<N> {
+    type Output = Interval;
+    fn index(&self, axis: usize) -> &Interval {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Cuboid<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut Interval {
+        &mut self.0[axis]
+    }
+}
+
+/// Set of points stored as disjoint boxes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CuboidSet<const N: usize>(Vec<Cuboid<N>>);
+
+impl<const N: usize> Default for CuboidSet<N> {
+    fn default() -> Self {
+        CuboidSet(vec![])
+    }
+}
+
+impl<const N: usize> CuboidSet<N> {
+    pub fn new() -> CuboidSet<N> {
+        CuboidSet::default()
+    }
+
+    pub fn cuboids(&self) -> &[Cuboid<N>] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Cuboid<N>> {
+        self.0.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Number of points in the set; saturates at `u64::MAX`
+    pub fn volume(&self) -> u64 {
+        self.0
+            .iter()
+            .map(Cuboid::volume)
+            .fold(0, u64::saturating_add)
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.0.iter().any(|c| c.contains(point))
+    }
+
+    /// Adds the points of `cuboid` that are not in the set yet
+    pub fn insert(&mut self, cuboid: Cuboid<N>) {
+        let pieces = self.0.iter().fold(vec![cuboid], |pieces, c| {
+            pieces.iter().flat_map(|p| p.subtract(c)).collect()
+        });
+        self.0.extend(pieces);
+    }
+
+    pub fn union(&self, other: &CuboidSet<N>) -> CuboidSet<N> {
+        let mut result = self.clone();
+        for c in &other.0 {
+            result.insert(*c);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &CuboidSet<N>) -> CuboidSet<N> {
+        CuboidSet(
+            self.0
+                .iter()
+                .flat_map(|a| other.0.iter().filter_map(|b| a.intersection(b)))
+                .collect(),
+        )
+    }
+
+    pub fn subtract(&self, other: &CuboidSet<N>) -> CuboidSet<N> {
+        CuboidSet(other.0.iter().fold(self.0.clone(), |pieces, c| {
+            pieces.iter().flat_map(|p| p.subtract(c)).collect()
+        }))
+    }
+}
+
+impl<const N: usize> From<Cuboid<N>> for CuboidSet<N> {
+    fn from(cuboid: Cuboid<N>) -> CuboidSet<N> {
+        let mut set = CuboidSet::new();
+        set.insert(cuboid);
+        set
+    }
+}
+
+impl<const N: usize> FromIterator<Cuboid<N>> for CuboidSet<N> {
+    fn from_iter<I: IntoIterator<Item = Cuboid<N>>>(iter: I) -> CuboidSet<N> {
+        let mut set = CuboidSet::new();
+        for cuboid in iter {
+            set.insert(cuboid);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+fn random_interval(g: &mut crate::prop::Gen) -> Interval {
+    // Sometimes empty
+    Interval::new(g.range(-10..=10), g.range(-10..=10))
+}
+
+#[cfg(test)]
+fn random_set(g: &mut crate::prop::Gen) -> IntervalSet {
+    let len = g.range(0..=4);
+    g.vec(len, random_interval).into_iter().collect()
+}
+
+#[test]
+fn test_interval() {
+    let i = Interval::new(2, 5);
+    assert_eq!(i.len(), 4);
+    assert_eq!(Interval::new(5, 2).len(), 0);
+    assert_eq!(Interval::spanning(5, 2), i);
+    assert!(i.contains(2) && i.contains(5) && !i.contains(6));
+    assert_eq!(
+        i.intersection(&Interval::new(4, 9)),
+        Some(Interval::new(4, 5))
+    );
+    assert_eq!(i.intersection(&Interval::new(6, 9)), None);
+    assert!(i.overlaps(&Interval::new(5, 5)));
+    assert_eq!(i.shift(-2), Interval::new(0, 3));
+    assert_eq!(i.split(4), (Interval::new(2, 3), Interval::new(4, 5)));
+    assert!(i.split(2).0.is_empty());
+    assert!(i.split(6).1.is_empty());
+    assert_eq!(Interval::new(i64::MIN, -1).len(), 1 << 63);
+    assert_eq!(i.split_after(4), (Interval::new(2, 4), Interval::new(5, 5)));
+    assert!(i.split_after(5).1.is_empty());
+}
+
+#[test]
+fn test_interval_extremes() {
+    let all = Interval::new(i64::MIN, i64::MAX);
+    assert_eq!(all.len(), u64::MAX);
+    assert_eq!(Interval::new(i64::MAX, i64::MAX).len(), 1);
+    let (below, above) = all.split(i64::MIN);
+    assert!(below.is_empty());
+    assert_eq!(above, all);
+    assert_eq!(all.split(i64::MAX).1, Interval::new(i64::MAX, i64::MAX));
+    let (below, above) = all.split_after(i64::MAX);
+    assert_eq!(below, all);
+    assert!(above.is_empty());
+    assert_eq!(
+        all.split_after(i64::MIN).0,
+        Interval::new(i64::MIN, i64::MIN)
+    );
+    let halves = IntervalSet::from_iter([Interval::new(i64::MIN, -1), Interval::new(0, i64::MAX)]);
+    assert_eq!(halves, IntervalSet::from(all));
+    assert_eq!(halves.len(), u64::MAX);
+    assert!(halves.subtract(&IntervalSet::from(all)).is_empty());
+    let rest = IntervalSet::from(all).subtract(&IntervalSet::from(Interval::new(i64::MIN, 0)));
+    assert_eq!(rest, IntervalSet::from(Interval::new(1, i64::MAX)));
+}
+
+#[test]
+fn test_interval_set() {
+    let set = [(5, 6), (1, 2), (3, 3), (10, 8)]
+        .into_iter()
+        .map(|(a, b)| Interval::new(a, b))
+        .collect::<IntervalSet>();
+    // Adjacent intervals are merged, empty ones dropped
+    assert_eq!(set.intervals(), &[Interval::new(1, 3), Interval::new(5, 6)]);
+    assert_eq!(set.to_string(), "{1..=3, 5..=6}");
+    assert_eq!((set.len(), set.min(), set.max()), (5, Some(1), Some(6)));
+    assert!(set.contains(3) && !set.contains(4));
+    let other = IntervalSet::from(Interval::new(3, 5));
+    assert_eq!(set.union(&other), IntervalSet::from(Interval::new(1, 6)));
+    assert_eq!(
+        set.subtract(&other).intervals(),
+        &[Interval::new(1, 2), Interval::new(6, 6)]
+    );
+    assert_eq!(
+        set.intersection(&other).intervals(),
+        &[Interval::new(3, 3), Interval::new(5, 5)]
+    );
+    assert_eq!(set.shift(10).min(), Some(11));
+    assert!(IntervalSet::from(Interval::new(1, 0)).is_empty());
+    // The first source that has a value moves it
+    let map = [(Interval::new(2, 5), 10), (Interval::new(0, 3), 20)];
+    assert_eq!(
+        set.map(&map).to_string(),
+        "{6..=6, 12..=13, 15..=15, 21..=21}"
+    );
+    assert_eq!(set.map(&[]), set);
+}
+
+#[test]
+fn test_interval_set_random() {
+    use std::collections::BTreeSet;
+    let values = |set: &IntervalSet| {
+        (-30..=30)
+            .filter(|v| set.contains(*v))
+            .collect::<BTreeSet<_>>()
+    };
+    let mut g = crate::prop::Gen::new(5, 0);
+    for _ in 0..500 {
+        let (a, b) = (random_set(&mut g), random_set(&mut g));
+        let (va, vb) = (values(&a), values(&b));
+        let union = a.union(&b);
+        let intersection = a.intersection(&b);
+        let difference = a.subtract(&b);
+        for set in [&a, &union, &intersection, &difference] {
+            // Normalized
+            assert!(set.0.iter().all(|i| !i.is_empty()));
+            assert!(set.0.windows(2).all(|w| w[0].end + 1 < w[1].start));
+            assert_eq!(set.len() as usize, values(set).len());
+        }
+        assert_eq!(values(&union), &va | &vb);
+        assert_eq!(values(&intersection), &va & &vb);
+        assert_eq!(values(&difference), &va - &vb);
+        let offset = g.range(-5..=5);
+        let shifted = va.iter().map(|v| v + offset).collect::<BTreeSet<_>>();
+        assert_eq!(values(&a.shift(offset)), shifted);
+        let map = [
+            (random_interval(&mut g), offset),
+            (random_interval(&mut g), 2),
+        ];
+        let mapped = va
+            .iter()
+            .map(|v| {
+                v + map
+                    .iter()
+                    .find(|(src, _)| src.contains(*v))
+                    .map_or(0, |m| m.1)
+            })
+            .collect::<BTreeSet<_>>();
+        assert_eq!(values(&a.map(&map)), mapped);
+        let mut inserted = a.clone();
+        for i in b.iter() {
+            inserted.insert(*i);
+        }
+        assert_eq!(inserted, union);
+    }
+}
+
+#[test]
+fn test_cuboid() {
+    let c = Cuboid::new([Interval::new(0, 3), Interval::new(0, 1)]);
+    assert_eq!(c.volume(), 8);
+    assert!(c.contains([3, 1]) && !c.contains([4, 1]));
+    let (below, above) = c.split(0, 1);
+    assert_eq!((below.volume(), above.volume()), (2, 6));
+    assert_eq!(c.split(1, 0).0.volume(), 0);
+    let hole = Cuboid::new([Interval::new(1, 2), Interval::new(1, 1)]);
+    assert_eq!(c.intersection(&hole), Some(hole));
+    let pieces = c.subtract(&hole);
+    assert_eq!(pieces.iter().map(Cuboid::volume).sum::<u64>(), 6);
+    assert_eq!(c.subtract(&c.split(0, 10).1), vec![c]);
+    assert_eq!(c[1], Interval::new(0, 1));
+}
+
+#[test]
+fn test_cuboid_extremes() {
+    let all = Interval::new(i64::MIN, i64::MAX);
+    let c = Cuboid::new([all, all]);
+    assert_eq!(c.volume(), u64::MAX);
+    let top = Cuboid::new([all, Interval::new(0, i64::MAX)]);
+    assert_eq!(
+        c.subtract(&top),
+        vec![Cuboid::new([all, Interval::new(i64::MIN, -1)])]
+    );
+    let bottom = Cuboid::new([all, Interval::new(i64::MIN, 0)]);
+    assert_eq!(
+        c.subtract(&bottom),
+        vec![Cuboid::new([all, Interval::new(1, i64::MAX)])]
+    );
+    assert!(c.subtract(&c).is_empty());
+    let set = CuboidSet::from(top).union(&CuboidSet::from(bottom));
+    assert_eq!(set.volume(), u64::MAX);
+    assert!(set.subtract(&CuboidSet::from(c)).is_empty());
+}
+
+#[test]
+fn test_cuboid_set_random() {
+    let mut g = crate::prop::Gen::new(3, 0);
+    let cuboid = |g: &mut crate::prop::Gen| {
+        let i = |g: &mut crate::prop::Gen| {
+            let start = g.range(0..=5);
+            Interval::new(start, start + g.range(-1..=3))
+        };
+        Cuboid::new([i(g), i(g), i(g)])
+    };
+    let points = (0..=8)
+        .flat_map(|x| (0..=8).flat_map(move |y| (0..=8).map(move |z| [x, y, z])))
+        .collect::<Vec<_>>();
+    for _ in 0..200 {
+        let (len_a, len_b) = (g.range(0..=3), g.range(0..=3));
+        let a = g.vec(len_a, cuboid).into_iter().collect::<CuboidSet<3>>();
+        let b = g.vec(len_b, cuboid).into_iter().collect::<CuboidSet<3>>();
+        let union = a.union(&b);
+        let intersection = a.intersection(&b);
+        let difference = a.subtract(&b);
+        for set in [&a, &union, &intersection, &difference] {
+            let count = points.iter().filter(|p| set.contains(**p)).count();
+            // Disjoint
+            assert_eq!(set.volume() as usize, count);
+        }
+        for p in &points {
+            let (in_a, in_b) = (a.contains(*p), b.contains(*p));
+            assert_eq!(union.contains(*p), in_a || in_b);
+            assert_eq!(intersection.contains(*p), in_a && in_b);
+            assert_eq!(difference.contains(*p), in_a && !in_b);
+        }
+    }
+}
@@ -0,0 +1,306 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Images of grids
+//!
+//! An [`Image`] has a colour per cell, usually mapped from the cells of
+//! a [`Grid`], and paths drawn over them through the centres of the
+//! cells. It can be written as PNG or SVG. Solvers show what they've
+//! found with [`dump`], which only builds and writes the image when
+//! `AOC_RENDER` has the output directory; `AOC_RENDER_FORMAT=svg`
+//! writes SVG instead of PNG. Benchmarks don't write images.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+
+use crate::grid::{Grid, Pos};
+
+/// Environment variable with the directory of the [`dump`] images
+pub const ENV: &str = "AOC_RENDER";
+/// Environment variable with the format of the [`dump`] images
+pub const ENV_FORMAT: &str = "AOC_RENDER_FORMAT";
+
+/// Pixels per cell in PNG images
+pub const SCALE: usize = 8;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+    pub const DARK: Rgb = Rgb(40, 40, 40);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 180, 60);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(240, 200, 40);
+
+    /// Colour between `self` (`t = 0.0`) and `other` (`t = 1.0`)
+    pub fn blend(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Coloured cells with paths over them
+#[derive(Debug, Clone)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    cells: Vec<Rgb>,
+    paths: Vec<(Vec<Pos>, Rgb)>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            cells: vec![background; width * height],
+            paths: vec![],
+        }
+    }
+
+    /// Image with the cells of `grid` coloured by `colour`
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl Fn(&T) -> Rgb) -> Image {
+        let mut image = Image::new(grid.width(), grid.height(), Rgb::BLACK);
+        for (pos, cell) in grid.iter() {
+            image.set(pos, colour(cell));
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<Rgb> {
+        (pos.x < self.width && pos.y < self.height).then(|| self.cells[pos.y * self.width + pos.x])
+    }
+
+    /// Colours the cell at `pos`, if it's in the image
+    pub fn set(&mut self, pos: Pos, colour: Rgb) {
+        if pos.x < self.width && pos.y < self.height {
+            self.cells[pos.y * self.width + pos.x] = colour;
+        }
+    }
+
+    /// Colours the cells at `positions`
+    pub fn overlay(&mut self, positions: impl IntoIterator<Item = Pos>, colour: Rgb) {
+        for pos in positions {
+            self.set(pos, colour);
+        }
+    }
+
+    /// Draws a line through the centres of the cells of `path`
+    pub fn path(&mut self, path: impl IntoIterator<Item = Pos>, colour: Rgb) {
+        self.paths.push((path.into_iter().collect(), colour));
+    }
+
+    pub fn to_svg(&self) -> String {
+        let mut svg = format!(
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" ",
+                "width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">\n"
+            ),
+            self.width,
+            self.height,
+            self.width * SCALE,
+            self.height * SCALE
+        );
+        // One rectangle per run of cells with the same colour
+        for y in 0..self.height {
+            let row = &self.cells[y * self.width..(y + 1) * self.width];
+            let mut x = 0;
+            while x < self.width {
+                let run = row[x..].iter().take_while(|c| **c == row[x]).count();
+                svg.push_str(&format!(
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"{}\"/>\n",
+                    x, y, run, row[x]
+                ));
+                x += run;
+            }
+        }
+        for (path, colour) in &self.paths {
+            let points = path
+                .iter()
+                .map(|p| format!("{}.5,{}.5", p.x, p.y))
+                .collect::<Vec<_>>();
+            svg.push_str(&format!(
+                concat!(
+                    "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" ",
+                    "stroke-width=\"0.3\" stroke-linejoin=\"round\" ",
+                    "shape-rendering=\"auto\"/>\n"
+                ),
+                points.join(" "),
+                colour
+            ));
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// RGB pixels, `scale` per cell in each direction
+    pub fn pixels(&self, scale: usize) -> Vec<Rgb> {
+        let width = self.width * scale;
+        let mut pixels = vec![Rgb::BLACK; width * self.height * scale];
+        for (i, pixel) in pixels.iter_mut().enumerate() {
+            let (x, y) = (i % width / scale, i / width / scale);
+            *pixel = self.cells[y * self.width + x];
+        }
+        let brush = (scale / 3).max(1);
+        for (path, colour) in &self.paths {
+            let centre = |p: &Pos| {
+                let c = |v: usize| (v * scale + scale / 2) as i64;
+                (c(p.x), c(p.y))
+            };
+            let points = path.iter().map(centre).collect::<Vec<_>>();
+            let segments = points.windows(2).map(|w| (w[0], w[1]));
+            let single = points.first().map(|&p| (p, p));
+            for ((x0, y0), (x1, y1)) in segments.chain(single) {
+                // Bresenham, with a square brush
+                let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+                let (sx, sy) = ((x1 - x0).signum(), (y1 - y0).signum());
+                let (mut x, mut y, mut err) = (x0, y0, dx + dy);
+                loop {
+                    for by in 0..brush as i64 {
+                        for bx in 0..brush as i64 {
+                            let px = (x + bx - brush as i64 / 2) as usize;
+                            let py = (y + by - brush as i64 / 2) as usize;
+                            if px < width && py < self.height * scale {
+                                pixels[py * width + px] = *colour;
+                            }
+                        }
+                    }
+                    if (x, y) == (x1, y1) {
+                        break;
+                    }
+                    let e2 = 2 * err;
+                    if e2 >= dy {
+                        err += dy;
+                        x += sx;
+                    }
+                    if e2 <= dx {
+                        err += dx;
+                        y += sy;
+                    }
+                }
+            }
+        }
+        pixels
+    }
+
+    pub fn to_png(&self, scale: usize) -> Result<Vec<u8>> {
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(
+            &mut png,
+            (self.width * scale) as u32,
+            (self.height * scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        let data = self
+            .pixels(scale)
+            .into_iter()
+            .flat_map(|Rgb(r, g, b)| [r, g, b])
+            .collect::<Vec<_>>();
+        writer.write_image_data(&data)?;
+        writer.finish()?;
+        Ok(png)
+    }
+
+    /// Writes the image as SVG or PNG, according to the extension
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let data = match path.extension().and_then(|e| e.to_str()) {
+            Some("svg") => self.to_svg().into_bytes(),
+            Some("png") => self.to_png(SCALE)?,
+            _ => return Err(eyre!("{}: unknown image format", path.display())),
+        };
+        fs::write(path, data).map_err(|e| eyre!("{}: {}", path.display(), e))
+    }
+}
+
+/// Writes the image built by `image` as `name` in the [`ENV`]
+/// directory, if it's set and this is not a benchmark
+pub fn dump(name: &str, image: impl FnOnce() -> Image) -> Result<()> {
+    let Some(dir) = std::env::var_os(ENV) else {
+        return Ok(());
+    };
+    if crate::bench::is_running() {
+        return Ok(());
+    }
+    let format = std::env::var(ENV_FORMAT).unwrap_or_else(|_| "png".to_string());
+    let path = PathBuf::from(dir).join(format!("{}.{}", name, format));
+    log::debug!("rendering {}", path.display());
+    image().save(&path)
+}
+
+#[test]
+fn test_rgb() {
+    assert_eq!(Rgb::RED.to_string(), "#dc322f");
+    assert_eq!(Rgb::BLACK.blend(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+    assert_eq!(Rgb::BLACK.blend(Rgb::WHITE, 2.0), Rgb::WHITE);
+}
+
+#[test]
+fn test_svg() -> Result<()> {
+    let grid = Grid::try_from(vec![vec![0, 0, 1], vec![1, 1, 1]])?;
+    let mut image = Image::from_grid(&grid, |&c| if c == 0 { Rgb::BLACK } else { Rgb::WHITE });
+    image.set(Pos::new(9, 9), Rgb::RED);
+    image.path([Pos::new(0, 0), Pos::new(0, 1)], Rgb::RED);
+    assert_eq!(image.get(Pos::new(1, 0)), Some(Rgb::BLACK));
+    assert_eq!(image.get(Pos::new(9, 9)), None);
+    let svg = image.to_svg();
+    assert!(svg.contains("viewBox=\"0 0 3 2\""));
+    assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"#000000\"/>"));
+    assert!(svg.contains("<rect x=\"0\" y=\"1\" width=\"3\" height=\"1\" fill=\"#ffffff\"/>"));
+    assert!(svg.contains("points=\"0.5,0.5 0.5,1.5\""));
+    Ok(())
+}
+
+#[test]
+fn test_png() -> Result<()> {
+    let mut image = Image::new(3, 2, Rgb::BLACK);
+    image.overlay([Pos::new(2, 0), Pos::new(2, 1)], Rgb::WHITE);
+    image.path([Pos::new(0, 0), Pos::new(1, 0), Pos::new(1, 1)], Rgb::RED);
+    let png = image.to_png(SCALE)?;
+    let decoder = png::Decoder::new(&png[..]);
+    let mut reader = decoder.read_info()?;
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data)?;
+    assert_eq!((info.width, info.height), (24, 16));
+    let pixel = |x: usize, y: usize| {
+        let i = 3 * (y * 24 + x);
+        Rgb(data[i], data[i + 1], data[i + 2])
+    };
+    assert_eq!(pixel(0, 0), Rgb::BLACK);
+    assert_eq!(pixel(20, 10), Rgb::WHITE);
+    // Centres of the cells of the path, and between them
+    assert_eq!(pixel(4, 4), Rgb::RED);
+    assert_eq!(pixel(8, 4), Rgb::RED);
+    assert_eq!(pixel(12, 8), Rgb::RED);
+    assert_eq!(pixel(4, 12), Rgb::BLACK);
+    Ok(())
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::Instant;
+
+use color_eyre::eyre::eyre;
+use color_eyre::Report;
+use color_eyre::Result;
+
+/// One of the two parts of a puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::A, Part::B];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Report;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" | "1" => Ok(Part::A),
+            "b" | "2" => Ok(Part::B),
+            other => Err(eyre!("invalid part {}", other)),
+        }
+    }
+}
+
+/// The answer to a puzzle part
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(i: $t) -> Self {
+                    Answer::Int(i as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{}", i),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// A day of the calendar
+///
+/// The solver value holds the parameters of the puzzle, and
+/// `Default` should return the ones used by the real input. Solvers
+/// are shared between threads when the calendar runs in parallel.
+pub trait Solver: Send + Sync {
+    const DAY: u32;
+    const PARTS: &'static [Part] = &Part::ALL;
+    type Input;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input>;
+    /// Inverse of `parse`: writes the input back as the puzzle text
+    fn unparse(&self, input: &Self::Input) -> String;
+    fn part1(&self, input: Self::Input) -> Result<Answer>;
+    fn part2(&self, input: Self::Input) -> Result<Answer>;
+
+    fn solve(&self, part: Part, input: Self::Input) -> Result<Answer> {
+        match part {
+            Part::A => self.part1(input),
+            Part::B => self.part2(input),
+        }
+    }
+}
+
+/// An answer, with the time spent parsing and solving
+#[derive(Debug, Clone)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Object-safe version of [`Solver`], used to keep solvers of
+/// different days together, and to run them in parallel
+pub trait DynSolver: Send + Sync {
+    fn day(&self) -> u32;
+    fn parts(&self) -> &'static [Part];
+    fn run(&self, part: Part, bufin: &mut dyn BufRead) -> Result<Answer>;
+    fn run_timed(&self, part: Part, bufin: &mut dyn BufRead) -> Result<Timed>;
+
+    fn name(&self, part: Part) -> String {
+        format!("day{:02}{}", self.day(), part)
+    }
+}
+
+impl<S: Solver> DynSolver for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn run(&self, part: Part, bufin: &mut dyn BufRead) -> Result<Answer> {
+        let input = self.parse(bufin)?;
+        self.solve(part, input)
+    }
+
+    fn run_timed(&self, part: Part, bufin: &mut dyn BufRead) -> Result<Timed> {
+        let start = Instant::now();
+        let input = self.parse(bufin)?;
+        let parse = start.elapsed();
+        let start = Instant::now();
+        let answer = self.solve(part, input)?;
+        let solve = start.elapsed();
+        Ok(Timed {
+            answer,
+            parse,
+            solve,
+        })
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Helpers to write parsed inputs back as puzzle text
+//!
+//! See [`Solver::unparse`](crate::Solver::unparse).
+
+use std::fmt::{Display, Write};
+
+/// Items separated by `sep`
+pub fn join<T: Display>(items: impl IntoIterator<Item = T>, sep: &str) -> String {
+    let mut out = String::new();
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(sep);
+        }
+        write!(out, "{}", item).unwrap();
+    }
+    out
+}
+
+/// One item per line, each one ending with a newline
+pub fn lines<T: Display>(items: impl IntoIterator<Item = T>) -> String {
+    let mut out = String::new();
+    for item in items {
+        writeln!(out, "{}", item).unwrap();
+    }
+    out
+}
+
+/// Inverse of [`parser::char_grid`](crate::parser::char_grid): one
+/// row per line, each cell written with `Display`
+pub fn char_grid<T: Display>(rows: &[Vec<T>]) -> String {
+    lines(rows.iter().map(|row| join(row, "")))
+}
+
+#[test]
+fn test_unparse() {
+    assert_eq!(join([1, 2, 3], ", "), "1, 2, 3");
+    assert_eq!(join(Vec::<u32>::new(), ", "), "");
+    assert_eq!(lines(["a", "b"]), "a\nb\n");
+    assert_eq!(char_grid(&[vec![1, 2], vec![3, 4]]), "12\n34\n");
+}
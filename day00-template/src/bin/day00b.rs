@@ -0,0 +1,9 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use day00::*;
+
+fn main() -> Result<()> {
+    do_main(&Day00, Part::B)
+}
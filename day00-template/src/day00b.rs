@@ -0,0 +1,9 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<u32>) -> Result<u32> {
+    Ok(input.iter().sum())
+}
@@ -4,6 +4,9 @@
 
 pub use aoc::*;
 
+pub mod day00a;
+pub mod day00b;
+
 pub const EXAMPLE: &str = "0\n";
 
 pub mod parser {
@@ -32,3 +35,42 @@ fn test() -> Result<()> {
     assert_eq!(input.len(), 1);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day00;
+
+impl Solver for Day00 {
+    const DAY: u32 = 0;
+    type Input = Vec<u32>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(input)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day00a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day00b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day00;
+    A: EXAMPLE => 1;
+    B: EXAMPLE => 0;
+}
+
+impl prop::Generate for Day00 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let len = g.len(1);
+        g.vec(len, |g| g.range(0..=1000))
+    }
+}
+
+aoc::properties!(Day00);
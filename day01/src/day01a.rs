@@ -0,0 +1,32 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use aoc::parser::InputError;
+
+use crate::*;
+
+fn firstnum(input: &str) -> parser::IResult<&str, u32> {
+    let (input, _) = parser::character::alpha0(input)?;
+    let (input, numstr) = parser::character::one_of("0123456789")(input)?;
+    let (input, _) = parser::character::alphanumeric0(input)?;
+    Ok((input, numstr.to_digit(10).unwrap()))
+}
+
+pub fn process(lines: Vec<String>) -> Result<u32> {
+    Ok(lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let no_digit = |_| Error::Parse(InputError::at(i + 1, 1, &line, "no digit"));
+            let first = parser::all_consuming(firstnum)(&line).map_err(no_digit)?.1;
+            let reversed = line.chars().rev().collect::<String>();
+            let last = parser::all_consuming(firstnum)(&reversed)
+                .map_err(no_digit)?
+                .1;
+            Ok(first * 10 + last)
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .sum())
+}
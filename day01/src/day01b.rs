@@ -0,0 +1,51 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use regex::Regex;
+
+use aoc::parser::InputError;
+
+use crate::*;
+
+fn readnum(s: &str) -> Option<u32> {
+    s.parse::<u32>().ok().or(match s {
+        "zero" => Some(0),
+        "one" => Some(1),
+        "two" => Some(2),
+        "three" => Some(3),
+        "four" => Some(4),
+        "five" => Some(5),
+        "six" => Some(6),
+        "seven" => Some(7),
+        "eight" => Some(8),
+        "nine" => Some(9),
+        _ => None,
+    })
+}
+
+pub fn process(lines: Vec<String>) -> Result<u32> {
+    let re1 = Regex::new(r"([0-9]|zero|one|two|three|four|five|six|seven|eight|nine)")?;
+    let re2 = Regex::new(r"([0-9]|enin|thgie|neves|xis|evif|ruof|eerht|owt|eno|orez)")?;
+    Ok(lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let invalid = |column: usize, message: &str| {
+                Error::Parse(InputError::at(i + 1, column, &line, message))
+            };
+            let first = re1.find(&line).ok_or_else(|| invalid(1, "no digit"))?;
+            let linerev = line.chars().rev().collect::<String>();
+            let lastrev = re2.find(&linerev).ok_or_else(|| invalid(1, "no digit"))?;
+            let last = lastrev.as_str().chars().rev().collect::<String>();
+            let first = readnum(first.as_str())
+                .ok_or_else(|| invalid(first.start() + 1, "invalid number"))?;
+            let last = readnum(&last)
+                .ok_or_else(|| invalid(line.len() - lastrev.end() + 1, "invalid number"))?;
+            let value = first * 10 + last;
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .sum())
+}
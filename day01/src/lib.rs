@@ -4,6 +4,9 @@
 
 pub use aoc::*;
 
+pub mod day01a;
+pub mod day01b;
+
 pub const EXAMPLE1: &str = "1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
@@ -41,3 +44,61 @@ fn test() -> Result<()> {
     assert_eq!(parser::parse(EXAMPLE2.as_bytes())?.len(), 7);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u32 = 1;
+    type Input = Vec<String>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(input)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day01a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day01b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day01;
+    A: EXAMPLE1 => 142;
+    B: EXAMPLE2 => 281;
+}
+
+impl prop::Generate for Day01 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let words = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let len = g.len(1);
+        g.vec(len, |g| {
+            let tokens = g.len(0);
+            let mut line = g.char("123456789").to_string();
+            for _ in 0..tokens {
+                let token = match g.range(0..=2) {
+                    0 => g.char("0123456789").to_string(),
+                    1 => g.choose(&words).to_string(),
+                    _ => g.char("abcdefghijklmnopqrstuvwxyz").to_string(),
+                };
+                if g.bool() {
+                    line.push_str(&token);
+                } else {
+                    line.insert_str(0, &token);
+                }
+            }
+            line
+        })
+    }
+}
+
+aoc::properties!(Day01);
@@ -4,34 +4,6 @@
 
 use day02::*;
 
-use rayon::prelude::*;
-
-fn process(bufin: impl BufRead) -> Result<u32> {
-    let input = parser::parse(bufin)?;
-    Ok(input
-        .into_par_iter()
-        .map(|game| {
-            game.into_par_iter()
-                .reduce(Set::new, |maxset, sets| {
-                    sets.into_iter().fold(maxset, |mut maxset, (color, num)| {
-                        let e = maxset.entry(color).or_default();
-                        *e = std::cmp::max(*e, num);
-                        maxset
-                    })
-                })
-                .into_par_iter()
-                .map(|(_, num)| num)
-                .product::<u32>()
-        })
-        .sum())
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, 2286);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(&Day02, Part::B)
 }
@@ -0,0 +1,20 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<Game>) -> Result<usize> {
+    let limits = [(Color::Red, 12), (Color::Green, 13), (Color::Blue, 14)]
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+    Ok(input
+        .into_iter()
+        .enumerate()
+        .filter_map(|(gameid, game)| {
+            game.into_iter()
+                .all(|sets| sets.into_iter().all(|(color, num)| num <= limits[&color]))
+                .then_some(gameid + 1)
+        })
+        .sum())
+}
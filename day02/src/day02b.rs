@@ -0,0 +1,24 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use rayon::prelude::*;
+
+pub fn process(input: Vec<Game>) -> Result<u32> {
+    Ok(input
+        .into_par_iter()
+        .map(|game| {
+            game.into_iter()
+                .flatten()
+                .fold(BTreeMap::<Color, u32>::new(), |mut maxset, (color, num)| {
+                    let e = maxset.entry(color).or_default();
+                    *e = std::cmp::max(*e, num);
+                    maxset
+                })
+                .into_values()
+                .product::<u32>()
+        })
+        .sum())
+}
@@ -5,6 +5,10 @@
 pub use aoc::*;
 
 pub use std::collections::BTreeMap;
+use std::fmt;
+
+pub mod day02a;
+pub mod day02b;
 
 pub const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
@@ -20,7 +24,18 @@ pub enum Color {
     Blue,
 }
 
-pub type Set = BTreeMap<Color, u32>;
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Red => write!(f, "red"),
+            Color::Green => write!(f, "green"),
+            Color::Blue => write!(f, "blue"),
+        }
+    }
+}
+
+/// Cubes drawn, in the order of the input
+pub type Set = Vec<(Color, u32)>;
 pub type Game = Vec<Set>;
 
 pub mod parser {
@@ -55,9 +70,7 @@ pub mod parser {
     }
 
     fn set(input: &str) -> IResult<&str, Set> {
-        let (input, setlist) = multi::separated_list1(bytes::tag(", "), entry)(input)?;
-        let set = setlist.into_iter().collect::<Set>();
-        Ok((input, set))
+        multi::separated_list1(bytes::tag(", "), entry)(input)
     }
 
     fn game(input: &str) -> IResult<&str, Game> {
@@ -79,3 +92,58 @@ fn test() -> Result<()> {
     assert_eq!(parser::parse(EXAMPLE.as_bytes())?.len(), 5);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<Game>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(input.iter().enumerate().map(|(i, game)| {
+            let sets = game.iter().map(|set| {
+                unparse::join(
+                    set.iter().map(|(color, num)| format!("{} {}", num, color)),
+                    ", ",
+                )
+            });
+            format!("Game {}: {}", i + 1, unparse::join(sets, "; "))
+        }))
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day02a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day02b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day02;
+    A: EXAMPLE => 8;
+    B: EXAMPLE => 2286;
+}
+
+impl prop::Generate for Day02 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let len = g.len(1);
+        g.vec(len, |g| {
+            let sets = g.range(1..=6);
+            g.vec(sets, |g| {
+                let mut colors = vec![Color::Red, Color::Green, Color::Blue];
+                g.shuffle(&mut colors);
+                colors.truncate(g.range(1..=3));
+                colors.into_iter().map(|c| (c, g.range(1..=20))).collect()
+            })
+        })
+    }
+}
+
+aoc::properties!(Day02);
@@ -0,0 +1,27 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<Vec<Cell>>) -> Result<u32> {
+    let mut grid = Grid::try_from(input)?;
+    let mut numbers = vec![];
+    // Look for symbols:
+    for qa_symbol in grid.iter_pos() {
+        if !matches!(grid[qa_symbol], Cell::Symbol(_)) {
+            continue;
+        }
+        // Check adjacencies, with diagonals:
+        for dir in Dir::iter::<true>() {
+            let Some(qa_adj) = grid.go(qa_symbol, dir) else {
+                continue;
+            };
+            let Ok(number) = grid_get_number(&mut grid, qa_adj) else {
+                continue;
+            };
+            numbers.push(number);
+        }
+    }
+    Ok(numbers.into_iter().sum())
+}
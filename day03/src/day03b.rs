@@ -0,0 +1,33 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<Vec<Cell>>) -> Result<u32> {
+    let mut grid = Grid::try_from(input)?;
+    let mut numbers = vec![];
+    // Look for symbols:
+    for qa_symbol in grid.iter_pos() {
+        if grid[qa_symbol] != Cell::Symbol('*') {
+            continue;
+        }
+        // Check adjacencies, with diagonals:
+        let mut this_gear = vec![];
+        for dir in Dir::iter::<true>() {
+            let Some(qa_adj) = grid.go(qa_symbol, dir) else {
+                continue;
+            };
+            let Ok(number) = grid_get_number(&mut grid, qa_adj) else {
+                continue;
+            };
+            this_gear.push(number);
+        }
+        if this_gear.len() != 2 {
+            // Not a gear
+            continue;
+        }
+        numbers.push(this_gear[0] * this_gear[1]);
+    }
+    Ok(numbers.into_iter().sum())
+}
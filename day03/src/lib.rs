@@ -6,6 +6,9 @@ pub use aoc::*;
 
 use std::fmt;
 
+pub mod day03a;
+pub mod day03b;
+
 pub const EXAMPLE: &str = "467..114..
 ...*......
 ..35..633.
@@ -36,6 +39,18 @@ impl Cell {
     }
 }
 
+impl TryFrom<char> for Cell {
+    type Error = aoc::Error;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Cell::Empty),
+            '0'..='9' => Ok(Cell::Digit(c)),
+            '#' | '*' | '+' | '$' | '&' | '%' | '=' | '@' | '/' | '-' => Ok(Cell::Symbol(c)),
+            other => Err(Error::invalid_char("cell", other)),
+        }
+    }
+}
+
 impl fmt::Display for Cell {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -46,11 +61,9 @@ impl fmt::Display for Cell {
     }
 }
 
-use sqrid::postrait::PosT;
+pub use aoc::grid::Pos;
 pub use sqrid::Dir;
-pub type Sqrid = sqrid::sqrid_create!(140, 140, true);
-pub type Pos = sqrid::pos_create!(Sqrid);
-pub type Grid = sqrid::grid_create!(Sqrid, Cell);
+pub type Grid = aoc::grid::Grid<Cell>;
 
 pub fn grid_get_number(grid: &mut Grid, qa_digit: Pos) -> Result<u32> {
     if !matches!(grid[qa_digit], Cell::Digit(_)) {
@@ -62,22 +75,18 @@ pub fn grid_get_number(grid: &mut Grid, qa_digit: Pos) -> Result<u32> {
     }
     // Found a digit, go left:
     let qa_start = std::iter::successors(Some(qa_digit), |pos| {
-        (*pos + Dir::W)
-            .ok()
+        grid.go(*pos, Dir::W)
             .filter(|pos| matches!(grid[pos], Cell::Digit(_)))
     })
     .last()
     .ok_or_else(|| eyre!("could not find first digit from {:?}", qa_digit))?;
     // We are at the start of the number, pick up the digits
-    let number_str = Pos::iter_range(qa_start, Pos::BOTTOM_RIGHT)
+    let number_str = std::iter::successors(Some(qa_start), |pos| grid.go(*pos, Dir::E))
         .map_while(|pos| grid[pos].digit().ok())
         .collect::<String>();
     // Empty the used cells:
-    for pos in Pos::iter_range(qa_start, Pos::BOTTOM_RIGHT) {
-        if grid[pos].digit().is_err() {
-            break;
-        }
-        grid[pos] = Cell::Empty;
+    for x in qa_start.x..qa_start.x + number_str.len() {
+        grid[Pos::new(x, qa_start.y)] = Cell::Empty;
     }
     number_str
         .parse::<u32>()
@@ -89,38 +98,71 @@ pub mod parser {
 
     use super::*;
 
-    fn empty(input: &str) -> IResult<&str, Cell> {
-        let (input, _) = bytes::tag(".")(input)?;
-        Ok((input, Cell::Empty))
+    pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
+        aoc::parse_with!(char_grid, bufin)
     }
+}
 
-    fn digit(input: &str) -> IResult<&str, Cell> {
-        let (input, digit) = character::one_of("0123456789")(input)?;
-        Ok((input, Cell::Digit(digit)))
-    }
+#[test]
+fn test() -> Result<()> {
+    assert_eq!(parser::parse(EXAMPLE.as_bytes())?.len(), 10);
+    Ok(())
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u32 = 3;
+    type Input = Vec<Vec<Cell>>;
 
-    fn symbol(input: &str) -> IResult<&str, Cell> {
-        let (input, symbol) = character::one_of("#*+$&%=@/-")(input)?;
-        Ok((input, Cell::Symbol(symbol)))
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
     }
 
-    fn cell(input: &str) -> IResult<&str, Cell> {
-        branch::alt((empty, branch::alt((digit, symbol))))(input)
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::char_grid(input)
     }
 
-    fn line(input: &str) -> IResult<&str, Vec<Cell>> {
-        let (input, line) = multi::many1(cell)(input)?;
-        let (input, _) = character::newline(input)?;
-        Ok((input, line))
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day03a::process(input)?.into())
     }
 
-    pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
-        aoc::parse_with!(multi::many1(line), bufin)
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day03b::process(input)?.into())
     }
 }
 
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(parser::parse(EXAMPLE.as_bytes())?.len(), 10);
-    Ok(())
+aoc::examples! {
+    Day03;
+    A: EXAMPLE => 4361;
+    B: EXAMPLE => 467835;
+}
+
+impl prop::Generate for Day03 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let (width, height) = (g.len(1), g.len(1));
+        g.vec(height, |g| {
+            let mut row = Vec::with_capacity(width);
+            while row.len() < width {
+                if g.one_in(3) {
+                    // Numbers have up to 3 digits
+                    let digits = g.range(1..=3).min(width - row.len());
+                    for _ in 0..digits {
+                        row.push(Cell::Digit(g.char("0123456789")));
+                    }
+                }
+                if row.len() < width {
+                    row.push(if g.one_in(4) {
+                        Cell::Symbol(g.char("#*+$&%=@/-"))
+                    } else {
+                        Cell::Empty
+                    });
+                }
+            }
+            row
+        })
+    }
 }
+
+aoc::properties!(Day03);
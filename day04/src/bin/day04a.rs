@@ -4,22 +4,6 @@
 
 use day04::*;
 
-fn process(bufin: impl BufRead) -> Result<u32> {
-    let input = parser::parse(bufin)?;
-    Ok(input
-        .into_iter()
-        .map(|(winners, have)| have.into_iter().filter(|h| winners.contains(h)).count() as u32)
-        .filter(|m| m > &0)
-        .map(|m| 2_u32.pow(m - 1))
-        .sum())
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, 13);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(&Day04, Part::A)
 }
@@ -0,0 +1,14 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<(Vec<u32>, Vec<u32>)>) -> Result<u32> {
+    Ok(input
+        .into_iter()
+        .map(|(winners, have)| have.into_iter().filter(|h| winners.contains(h)).count() as u32)
+        .filter(|m| m > &0)
+        .map(|m| 2_u32.pow(m - 1))
+        .sum())
+}
@@ -0,0 +1,28 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::BTreeMap;
+
+pub fn process(input: Vec<(Vec<u32>, Vec<u32>)>) -> Result<usize> {
+    let num_cards = input.len();
+    let matches = input
+        .into_iter()
+        .enumerate()
+        .map(|(id, (winners, have))| (id, have.into_iter().filter(|h| winners.contains(h)).count()))
+        .collect::<BTreeMap<_, _>>();
+    let mut copies = BTreeMap::<usize, usize>::default();
+    for (id0, matches) in matches {
+        let c = copies.entry(id0).or_insert(0);
+        // Add one for the original card:
+        *c += 1;
+        let card_copies = *c;
+        for id in (id0 + 1)..std::cmp::min(num_cards, id0 + matches + 1) {
+            let e = copies.entry(id).or_insert(0);
+            *e += card_copies;
+        }
+    }
+    Ok(copies.into_values().sum())
+}
@@ -4,6 +4,9 @@
 
 pub use aoc::*;
 
+pub mod day04a;
+pub mod day04b;
+
 pub const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
 Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
 Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
@@ -41,3 +44,60 @@ fn test() -> Result<()> {
     assert_eq!(parser::parse(EXAMPLE.as_bytes())?.len(), 6);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u32 = 4;
+    type Input = Vec<(Vec<u32>, Vec<u32>)>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        let width = input.len().to_string().len();
+        let nums = |nums: &Vec<u32>| unparse::join(nums.iter().map(|n| format!("{:>2}", n)), " ");
+        unparse::lines(input.iter().enumerate().map(|(i, (winners, have))| {
+            format!(
+                "Card {:>width$}: {} | {}",
+                i + 1,
+                nums(winners),
+                nums(have),
+                width = width
+            )
+        }))
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day04a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day04b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day04;
+    A: EXAMPLE => 13;
+    B: EXAMPLE => 30;
+}
+
+impl prop::Generate for Day04 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // All cards have the same number of distinct numbers
+        let (num_winners, num_have) = (g.range(1..=10), g.range(1..=25));
+        let len = g.len(1);
+        g.vec(len, |g| {
+            let mut numbers = (1..=99).collect::<Vec<u32>>();
+            g.shuffle(&mut numbers);
+            let winners = numbers[..num_winners].to_vec();
+            g.shuffle(&mut numbers);
+            (winners, numbers[..num_have].to_vec())
+        })
+    }
+}
+
+aoc::properties!(Day04);
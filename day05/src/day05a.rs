@@ -0,0 +1,24 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: (Vec<usize>, Vec<Vec<Entry>>)) -> Result<usize> {
+    let (seeds, almanac) = input;
+    seeds
+        .into_iter()
+        .map(|seed| {
+            almanac.iter().fold(seed, |seed, map| {
+                map.iter()
+                    .find_map(|entry| {
+                        let (dest, source, len) = *entry;
+                        #[allow(clippy::unnecessary_lazy_evaluations)]
+                        (source <= seed && seed <= source + len).then(|| seed - source + dest)
+                    })
+                    .unwrap_or(seed)
+            })
+        })
+        .min()
+        .ok_or_else(|| Error::no_solution("no seeds").into())
+}
@@ -0,0 +1,64 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::ranges::{Interval, IntervalSet};
+
+/// Applies one map of the almanac; values outside of its sources
+/// are kept
+pub fn apply(map: &[(Interval, i64)], current: &IntervalSet) -> IntervalSet {
+    current.map(map)
+}
+
+#[test]
+fn test_apply() {
+    let set = |ini, end| IntervalSet::from(Interval::new(ini, end));
+    let map = |ini, end, dest| vec![(Interval::new(ini, end), dest - ini)];
+    // disjunction:
+    assert_eq!(apply(&map(5, 6, 9), &set(2, 4)), set(2, 4));
+    // equal:
+    assert_eq!(apply(&map(2, 4, 9), &set(2, 4)), set(9, 11));
+    // contained in src:
+    assert_eq!(apply(&map(1, 5, 9), &set(2, 4)), set(10, 12));
+    // contains src:
+    assert_eq!(
+        apply(&map(2, 4, 9), &set(0, 6)),
+        set(0, 1).union(&set(5, 6)).union(&set(9, 11))
+    );
+    // overlap seed_ini first
+    assert_eq!(
+        apply(&map(2, 6, 9), &set(0, 4)),
+        set(0, 1).union(&set(9, 11))
+    );
+    // overlap src_ini first
+    assert_eq!(
+        apply(&map(0, 4, 9), &set(2, 6)),
+        set(5, 6).union(&set(11, 13))
+    );
+}
+
+pub fn process(input: (Vec<usize>, Vec<Vec<Entry>>)) -> Result<i64> {
+    let (seeds, almanac) = input;
+    let almanac = almanac
+        .into_iter()
+        .map(|a| {
+            a.into_iter()
+                .map(|(dest, ini, len)| {
+                    let src = Interval::new(ini as i64, (ini + len) as i64 - 1);
+                    (src, dest as i64 - ini as i64)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let seeds = seeds
+        .chunks_exact(2)
+        .map(|s| Interval::new(s[0] as i64, (s[0] + s[1]) as i64 - 1))
+        .collect::<IntervalSet>();
+    almanac
+        .iter()
+        .fold(seeds, |current, map| apply(map, &current))
+        .min()
+        .ok_or_else(|| Error::no_solution("no seeds").into())
+}
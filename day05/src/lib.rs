@@ -4,6 +4,9 @@
 
 pub use aoc::*;
 
+pub mod day05a;
+pub mod day05b;
+
 pub const EXAMPLE: &str = "seeds: 79 14 55 13
 
 seed-to-soil map:
@@ -41,6 +44,18 @@ humidity-to-location map:
 
 pub type Entry = (usize, usize, usize);
 
+/// Categories of the almanac; map `i` goes from category `i` to `i + 1`
+pub const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
 pub mod parser {
     use aoc::parser::*;
 
@@ -71,9 +86,10 @@ pub mod parser {
     }
 
     fn parse_all(input: &str) -> IResult<&str, (Vec<usize>, Vec<Vec<Entry>>)> {
-        let (input, seeds) = seeds(input)?;
+        let (input, seeds) = context("seeds", seeds)(input)?;
         let (input, _) = character::newline(input)?;
-        let (input, maps) = multi::separated_list1(character::newline, onemap)(input)?;
+        let maps = multi::separated_list1(character::newline, onemap);
+        let (input, maps) = context("maps", maps)(input)?;
         Ok((input, (seeds, maps)))
     }
 
@@ -87,5 +103,78 @@ fn test() -> Result<()> {
     let input = parser::parse(EXAMPLE.as_bytes())?;
     assert_eq!(input.0.len(), 4);
     assert_eq!(input.1.len(), 7);
+    let e = parser::parse("seeds: x\n".as_bytes()).unwrap_err();
+    assert!(e
+        .to_string()
+        .starts_with("parse error at line 1, column 8 in seeds:"));
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u32 = 5;
+    type Input = (Vec<usize>, Vec<Vec<Entry>>);
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        let (seeds, maps) = input;
+        let maps = maps.iter().enumerate().map(|(i, entries)| {
+            let name = match (CATEGORIES.get(i), CATEGORIES.get(i + 1)) {
+                (Some(src), Some(dst)) => format!("{}-to-{}", src, dst),
+                _ => format!("map{}", i),
+            };
+            let entries = entries
+                .iter()
+                .map(|(dst, src, len)| format!("{} {} {}", dst, src, len));
+            format!("{} map:\n{}", name, unparse::lines(entries))
+        });
+        format!(
+            "seeds: {}\n\n{}",
+            unparse::join(seeds, " "),
+            unparse::join(maps, "\n")
+        )
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day05a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day05b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day05;
+    A: EXAMPLE => 35;
+    B: EXAMPLE => 46;
+}
+
+impl prop::Generate for Day05 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let max = 100 * g.size();
+        let seeds = 2 * g.range(1..=4);
+        let seeds = g.vec(seeds, |g| g.range(0..=max));
+        let maps = g.vec(CATEGORIES.len() - 1, |g| {
+            // Source ranges don't overlap
+            let mut src = g.range(0..=max / 2);
+            let entries = g.range(1..=4);
+            let mut entries = g.vec(entries, |g| {
+                let len = g.range(1..=max / 4 + 1);
+                let entry = (g.range(0..=max), src, len);
+                src += len + g.range(0..=max / 4);
+                entry
+            });
+            g.shuffle(&mut entries);
+            entries
+        });
+        (seeds, maps)
+    }
+}
+
+aoc::properties!(Day05);
@@ -4,27 +4,6 @@
 
 use day06::*;
 
-fn process(bufin: impl BufRead) -> Result<usize> {
-    let input = parser::parse(bufin)?;
-    let (times, distances) = input;
-    let races = std::iter::zip(times, distances).collect::<Vec<_>>();
-    Ok(races
-        .into_iter()
-        .map(|(time, distance)| {
-            (0..=time)
-                .map(|button_time| button_time * (time - button_time))
-                .filter(|i| i > &distance)
-                .count()
-        })
-        .product())
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, 288);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(&Day06, Part::A)
 }
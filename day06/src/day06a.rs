@@ -0,0 +1,19 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: (Vec<u32>, Vec<u32>)) -> Result<usize> {
+    let (times, distances) = input;
+    let races = std::iter::zip(times, distances).collect::<Vec<_>>();
+    Ok(races
+        .into_iter()
+        .map(|(time, distance)| {
+            (0..=time)
+                .map(|button_time| button_time * (time - button_time))
+                .filter(|i| i > &distance)
+                .count()
+        })
+        .product())
+}
@@ -0,0 +1,29 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::fmt::Write;
+
+pub fn process(input: (Vec<u32>, Vec<u32>)) -> Result<usize> {
+    let (time_vec, distance_vec) = input;
+    let time = time_vec
+        .into_iter()
+        .fold(String::default(), |mut s, t| {
+            write!(s, "{}", t).unwrap();
+            s
+        })
+        .parse::<u64>()?;
+    let distance = distance_vec
+        .into_iter()
+        .fold(String::default(), |mut s, t| {
+            write!(s, "{}", t).unwrap();
+            s
+        })
+        .parse::<u64>()?;
+    Ok((0..=time)
+        .map(|button_time| button_time * (time - button_time))
+        .filter(|i| i > &distance)
+        .count())
+}
@@ -4,6 +4,9 @@
 
 pub use aoc::*;
 
+pub mod day06a;
+pub mod day06b;
+
 pub const EXAMPLE: &str = "Time:      7  15   30
 Distance:  9  40  200
 ";
@@ -39,3 +42,62 @@ fn test() -> Result<()> {
     assert_eq!(example.1.len(), 3);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u32 = 6;
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        let (times, distances) = input;
+        // Right-aligned columns, two spaces apart, as in the example
+        let widths = times
+            .iter()
+            .zip(distances)
+            .map(|(t, d)| t.to_string().len().max(d.to_string().len()))
+            .collect::<Vec<_>>();
+        let row = |label: &str, nums: &Vec<u32>| {
+            let cols = nums
+                .iter()
+                .zip(&widths)
+                .map(|(n, w)| format!("  {:>w$}", n, w = w));
+            format!("{:<9}{}\n", label, unparse::join(cols, ""))
+        };
+        row("Time:", times) + &row("Distance:", distances)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day06a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day06b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day06;
+    A: EXAMPLE => 288;
+    B: EXAMPLE => 71503;
+}
+
+impl prop::Generate for Day06 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // Part b joins the digits of all races, keep that small
+        let races = 1 + g.size() / 10;
+        let times = g.vec(races, |g| g.range(1..=99));
+        let distances = times
+            .iter()
+            .map(|t| g.range(0..=t * t / 4))
+            .collect::<Vec<_>>();
+        (times, distances)
+    }
+}
+
+aoc::properties!(Day06);
@@ -0,0 +1,17 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use itertools::Itertools;
+
+pub fn process(input: Vec<(Hand, i64)>) -> Result<i64> {
+    Ok(input
+        .into_iter()
+        .map(|(hand, bid)| (hand.value(false), bid))
+        .sorted()
+        .enumerate()
+        .map(|(i, (_hand, bid))| (i as i64 + 1) * bid)
+        .sum())
+}
@@ -9,6 +9,9 @@ use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 
+pub mod day07a;
+pub mod day07b;
+
 pub const EXAMPLE: &str = "32T3K 765
 T55J5 684
 KK677 28
@@ -138,10 +141,11 @@ impl Hand {
 }
 
 impl TryFrom<&[Card]> for Hand {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(cards: &[Card]) -> Result<Self, Self::Error> {
         if cards.len() != 5 {
-            return Err(eyre!("invalid length for hand"));
+            let message = format!("hand has {} cards, expected 5", cards.len());
+            return Err(Error::shape(message));
         }
         Ok(Hand([cards[0], cards[1], cards[2], cards[3], cards[4]]))
     }
@@ -151,7 +155,7 @@ impl FromStr for Hand {
     type Err = Report;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let cards = s.chars().map(Card).collect::<Vec<_>>();
-        Hand::try_from(cards.as_ref())
+        Ok(Hand::try_from(cards.as_ref())?)
     }
 }
 
@@ -221,3 +225,45 @@ fn test() -> Result<()> {
     assert_eq!(parser::parse(EXAMPLE.as_bytes())?.len(), 5);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u32 = 7;
+    type Input = Vec<(Hand, i64)>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(input.iter().map(|(hand, bid)| format!("{} {}", hand, bid)))
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day07a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day07b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day07;
+    A: EXAMPLE => 6440;
+    B: EXAMPLE => 5905;
+}
+
+impl prop::Generate for Day07 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let len = g.len(1);
+        g.vec(len, |g| {
+            let hand = Hand([(); 5].map(|_| Card(g.char("AKQJT98765432"))));
+            (hand, g.range(1..=1000))
+        })
+    }
+}
+
+aoc::properties!(Day07);
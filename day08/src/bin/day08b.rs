@@ -4,43 +4,6 @@
 
 use day08::*;
 
-use rayon::prelude::*;
-use std::collections::HashMap;
-
-fn process(bufin: impl BufRead) -> Result<usize> {
-    let (instrs, paths) = parser::parse(bufin)?;
-    let paths = paths.into_iter().collect::<HashMap<_, _>>();
-    let starts = paths
-        .keys()
-        .filter(|n| n.last_letter() == 'A')
-        .copied()
-        .collect::<Vec<_>>();
-    let cycle_lengths = starts
-        .into_par_iter()
-        .map(|initial| {
-            let mut curr = initial;
-            instrs
-                .iter()
-                .cycle()
-                .enumerate()
-                .find_map(|(steps, instr)| {
-                    curr = Node::follow(&paths[&curr], instr);
-                    (curr.last_letter() == 'Z').then_some(steps + 1)
-                })
-                .unwrap_or_else(|| unreachable!())
-        })
-        .collect::<Vec<_>>();
-    Ok(cycle_lengths.into_iter().reduce(num::integer::lcm).unwrap())
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE1.as_bytes())?, 2);
-    assert_eq!(process(EXAMPLE2.as_bytes())?, 6);
-    assert_eq!(process(EXAMPLE3.as_bytes())?, 6);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(&Day08, Part::B)
 }
@@ -0,0 +1,27 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::str::FromStr;
+
+pub fn process(input: (Vec<Instr>, Vec<Entry>)) -> Result<usize> {
+    let (instrs, entries) = input;
+    let network = network(entries);
+    let node_id = |name: &str| -> Result<NodeId> {
+        let node = Node::from_str(name)?;
+        network
+            .id(&node)
+            .ok_or_else(|| Error::shape(format!("node {} not found", node)).into())
+    };
+    let mut curr = node_id("AAA")?;
+    let target = node_id("ZZZ")?;
+    for (steps, instr) in instrs.iter().cycle().enumerate() {
+        curr = follow(&network, curr, *instr)?;
+        if curr == target {
+            return Ok(steps + 1);
+        }
+    }
+    Err(Error::shape("no instructions").into())
+}
@@ -0,0 +1,32 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use rayon::prelude::*;
+
+pub fn process(input: (Vec<Instr>, Vec<Entry>)) -> Result<usize> {
+    let (instrs, entries) = input;
+    let network = network(entries);
+    let starts = (0..network.len())
+        .filter(|&id| network.node(id).last_letter() == 'A')
+        .collect::<Vec<_>>();
+    let cycle_lengths = starts
+        .into_par_iter()
+        .map(|initial| {
+            let mut curr = initial;
+            for (steps, instr) in instrs.iter().cycle().enumerate() {
+                curr = follow(&network, curr, *instr)?;
+                if network.node(curr).last_letter() == 'Z' {
+                    return Ok(steps + 1);
+                }
+            }
+            Err(Error::shape("no instructions").into())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    cycle_lengths
+        .into_iter()
+        .reduce(num::integer::lcm)
+        .ok_or_else(|| Error::no_solution("no starting nodes").into())
+}
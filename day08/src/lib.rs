@@ -4,8 +4,14 @@
 
 pub use aoc::*;
 
+use aoc::graph::{Graph, NodeId};
+
+use std::fmt;
 use std::str::FromStr;
 
+pub mod day08a;
+pub mod day08b;
+
 pub const EXAMPLE1: &str = "RL
 
 AAA = (BBB, CCC)
@@ -40,13 +46,6 @@ XXX = (XXX, XXX)
 pub struct Node(pub [char; 3]);
 
 impl Node {
-    pub fn follow(opts: &(Node, Node), instr: &Instr) -> Node {
-        match instr {
-            Instr::L => opts.0,
-            Instr::R => opts.1,
-        }
-    }
-
     pub fn last_letter(&self) -> char {
         self.0[2]
     }
@@ -55,33 +54,52 @@ impl Node {
 impl FromStr for Node {
     type Err = Report;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Node::try_from(s.chars().collect::<Vec<_>>().as_ref())
+        Ok(Node::try_from(s.chars().collect::<Vec<_>>().as_ref())?)
     }
 }
 
 impl TryFrom<&[char]> for Node {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(chars: &[char]) -> Result<Self, Self::Error> {
         if chars.len() != 3 {
-            return Err(eyre!("invalid length for hand"));
+            let message = format!("node has {} letters, expected 3", chars.len());
+            return Err(Error::shape(message));
         }
         Ok(Node([chars[0], chars[1], chars[2]]))
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+impl fmt::Display for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0 {
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Instr {
     L,
     R,
 }
 
 impl TryFrom<char> for Instr {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             'L' => Ok(Instr::L),
             'R' => Ok(Instr::R),
-            other => Err(eyre!("invalid instruction letter {}", other)),
+            other => Err(Error::invalid_char("instruction letter", other)),
+        }
+    }
+}
+
+impl fmt::Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instr::L => write!(f, "L"),
+            Instr::R => write!(f, "R"),
         }
     }
 }
@@ -114,10 +132,10 @@ pub mod parser {
     }
 
     fn parse_all(input: &str) -> IResult<&str, (Vec<Instr>, Vec<Entry>)> {
-        let (input, instrs) = multi::many1(instr)(input)?;
+        let (input, instrs) = context("instructions", multi::many1(instr))(input)?;
         let (input, _) = character::newline(input)?;
         let (input, _) = character::newline(input)?;
-        let (input, connections) = multi::many1(connection)(input)?;
+        let (input, connections) = context("network", multi::many1(connection))(input)?;
         Ok((input, (instrs, connections)))
     }
 
@@ -136,3 +154,118 @@ fn test() -> Result<()> {
     assert_eq!(parser::parse(EXAMPLE3.as_bytes())?.1.len(), 8);
     Ok(())
 }
+
+/// The nodes, with an `L` and an `R` edge each
+pub type Network = Graph<Node, Instr>;
+
+pub fn network(entries: Vec<Entry>) -> Network {
+    let mut network = Network::new();
+    for (node, (left, right)) in entries {
+        network.add_edge(node, left, Instr::L);
+        network.add_edge(node, right, Instr::R);
+    }
+    network
+}
+
+/// Node reached from `id` by following `instr`
+pub fn follow(network: &Network, id: NodeId, instr: Instr) -> Result<NodeId> {
+    network
+        .edges(id)
+        .iter()
+        .find(|(_, i)| *i == instr)
+        .map(|(to, _)| *to)
+        .ok_or_else(|| {
+            let message = format!("node {} has no {} exit", network.node(id), instr);
+            Error::shape(message).into()
+        })
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u32 = 8;
+    type Input = (Vec<Instr>, Vec<Entry>);
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        let (instrs, entries) = input;
+        format!(
+            "{}\n\n{}",
+            unparse::join(instrs, ""),
+            unparse::lines(
+                entries
+                    .iter()
+                    .map(|(node, (left, right))| format!("{} = ({}, {})", node, left, right))
+            )
+        )
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day08a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day08b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day08;
+    A: EXAMPLE1 => 2;
+    A: EXAMPLE2 => 6;
+    B: EXAMPLE1 => 2;
+    B: EXAMPLE2 => 6;
+    B: EXAMPLE3 => 6;
+}
+
+impl prop::Generate for Day08 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let len = g.len(1);
+        let instrs = g.vec(len, |g| if g.bool() { Instr::L } else { Instr::R });
+        // Each ghost walks a chain from its start to its end, which
+        // loops back to the chain; some steps take a detour node
+        // that leads to the same place.
+        let mut names = 0;
+        let mut node = |last: char| {
+            names += 1;
+            let letter = |i: usize| char::from(b'B' + (i % 24) as u8);
+            Node([letter(names / 24), letter(names), last])
+        };
+        let mut entries = vec![];
+        for ghost in 0..g.range(1..=3) {
+            let (start, end) = if ghost == 0 {
+                (Node(['A'; 3]), Node(['Z'; 3]))
+            } else {
+                (node('A'), node('Z'))
+            };
+            let chain = g.range(0..=g.size());
+            let mut nodes = vec![start];
+            nodes.extend((0..chain).map(|_| node('X')));
+            nodes.push(end);
+            let back = nodes[1];
+            for (i, &n) in nodes.iter().enumerate() {
+                let next = nodes.get(i + 1).copied().unwrap_or(back);
+                if g.one_in(3) {
+                    let detour = node('X');
+                    entries.push((detour, (next, next)));
+                    let opts = if g.bool() {
+                        (next, detour)
+                    } else {
+                        (detour, next)
+                    };
+                    entries.push((n, opts));
+                } else {
+                    entries.push((n, (next, next)));
+                }
+            }
+        }
+        g.shuffle(&mut entries);
+        (instrs, entries)
+    }
+}
+
+aoc::properties!(Day08);
@@ -0,0 +1,19 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+fn calc_next(nums: &[i64]) -> i64 {
+    let mut lastsum = nums[nums.len() - 1];
+    let mut currdiff = diffs(nums);
+    while !currdiff.par_iter().all(|n| n == &0) {
+        lastsum += currdiff[currdiff.len() - 1];
+        currdiff = diffs(&currdiff);
+    }
+    lastsum
+}
+
+pub fn process(input: Vec<Vec<i64>>) -> Result<i64> {
+    Ok(input.par_iter().map(|v| calc_next(v)).sum())
+}
@@ -0,0 +1,21 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+fn calc_prev(nums: &[i64]) -> i64 {
+    let mut mult = -1;
+    let mut result = nums[0];
+    let mut currdiff = diffs(nums);
+    while !currdiff.par_iter().all(|n| n == &0) {
+        result += mult * currdiff[0];
+        mult *= -1;
+        currdiff = diffs(&currdiff);
+    }
+    result
+}
+
+pub fn process(input: Vec<Vec<i64>>) -> Result<i64> {
+    Ok(input.par_iter().map(|v| calc_prev(v)).sum())
+}
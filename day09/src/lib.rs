@@ -6,6 +6,9 @@ pub use aoc::*;
 
 use rayon::prelude::*;
 
+pub mod day09a;
+pub mod day09b;
+
 pub const EXAMPLE: &str = "0 3 6 9 12 15
 1 3 6 10 15 21
 10 13 16 21 30 45
@@ -36,3 +39,50 @@ fn test() -> Result<()> {
     assert_eq!(parser::parse(EXAMPLE.as_bytes())?.len(), 3);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u32 = 9;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(input.iter().map(|nums| unparse::join(nums, " ")))
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day09a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day09b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day09;
+    A: EXAMPLE => 114;
+    B: EXAMPLE => 2;
+}
+
+impl prop::Generate for Day09 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let len = g.len(1);
+        g.vec(len, |g| {
+            // Values of a polynomial
+            let degree = g.range(0..=3);
+            let coefs = g.vec(degree + 1, |g| g.range(-5..=5));
+            let len = g.len(1);
+            (0..len as i64)
+                .map(|x| coefs.iter().rev().fold(0, |acc, c| acc * x + c))
+                .collect()
+        })
+    }
+}
+
+aoc::properties!(Day09);
@@ -0,0 +1,28 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: (Vec<Vec<Cell>>, Pos)) -> Result<usize> {
+    let (rows, start) = input;
+    let grid = Grid::try_from(rows)?;
+    for qr0 in [Dir::N, Dir::E, Dir::S, Dir::W] {
+        let mut steps = 0;
+        let mut dir = qr0;
+        let mut pos = start;
+        while let Some(next_qa) = grid.go(pos, dir) {
+            pos = next_qa;
+            steps += 1;
+            if pos == start {
+                return Ok(steps / 2);
+            }
+            if let Some(next_qr) = next_qr(&grid, pos, dir) {
+                dir = next_qr;
+            } else {
+                break;
+            }
+        }
+    }
+    Err(Error::no_solution("no loop through start").into())
+}
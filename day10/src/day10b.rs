@@ -0,0 +1,74 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::geom::Point2;
+
+use std::collections::HashSet;
+
+fn calc_pipe(grid: &Grid, start: Pos) -> Result<Vec<Pos>> {
+    for qr0 in [Dir::N, Dir::E, Dir::S, Dir::W] {
+        let mut pipe = vec![start];
+        let mut dir = qr0;
+        let mut pos = start;
+        while let Some(next_qa) = grid.go(pos, dir) {
+            pos = next_qa;
+            pipe.push(pos);
+            if pos == start {
+                return Ok(pipe);
+            }
+            if let Some(next_qr) = next_qr(grid, pos, dir) {
+                dir = next_qr;
+            } else {
+                break;
+            }
+        }
+    }
+    Err(Error::no_solution("no loop through start").into())
+}
+
+/// Tiles enclosed by the pipe, found by crossing it along each row
+fn enclosed(grid: &Grid, pipe: &[Pos]) -> Vec<Pos> {
+    let on_pipe = pipe.iter().copied().collect::<HashSet<Pos>>();
+    let goes_north = |pos: Pos| match grid[pos] {
+        Cell::NS | Cell::NE | Cell::NW => true,
+        Cell::Start => {
+            let north = pos.step(Dir::N);
+            north.is_some()
+                && (north == pipe.get(1).copied() || north == pipe.iter().nth_back(1).copied())
+        }
+        _ => false,
+    };
+    let mut tiles = vec![];
+    for y in 0..grid.height() {
+        let mut inside = false;
+        for x in 0..grid.width() {
+            let pos = Pos::new(x, y);
+            if on_pipe.contains(&pos) {
+                inside ^= goes_north(pos);
+            } else if inside {
+                tiles.push(pos);
+            }
+        }
+    }
+    tiles
+}
+
+pub fn process(input: (Vec<Vec<Cell>>, Pos)) -> Result<usize> {
+    let (rows, start) = input;
+    let grid = Grid::try_from(rows)?;
+    let pipe = calc_pipe(&grid, start)?;
+    render::dump("day10b", || {
+        let mut image = render::Image::from_grid(&grid, |_| render::Rgb::DARK);
+        image.overlay(enclosed(&grid, &pipe), render::Rgb::GREEN);
+        image.path(pipe.iter().copied(), render::Rgb::YELLOW);
+        image
+    })?;
+    let mut pipe = pipe.into_iter().map(Point2::from).collect::<Vec<_>>();
+    // The start is repeated at the end
+    pipe.pop();
+    // Pick's theorem, with the pipe tiles as the boundary:
+    Ok(geom::interior_points(&pipe) as usize)
+}
@@ -4,6 +4,11 @@
 
 pub use aoc::*;
 
+use std::fmt;
+
+pub mod day10a;
+pub mod day10b;
+
 pub const EXAMPLE1: &str = "-L|F7
 7S-7|
 L|7||
@@ -60,10 +65,9 @@ impl Cell {
     }
 }
 
+pub use aoc::grid::Pos;
 pub use sqrid::Dir;
-pub type Sqrid = sqrid::sqrid_create!(140, 140, false);
-pub type Pos = sqrid::pos_create!(Sqrid);
-pub type Grid = sqrid::grid_create!(Sqrid, Cell);
+pub type Grid = aoc::grid::Grid<Cell>;
 
 pub fn next_qr(grid: &Grid, pos: Pos, dir: Dir) -> Option<Dir> {
     match (grid[pos], dir) {
@@ -84,7 +88,7 @@ pub fn next_qr(grid: &Grid, pos: Pos, dir: Dir) -> Option<Dir> {
 }
 
 impl TryFrom<char> for Cell {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '|' => Ok(Cell::NS),
@@ -95,37 +99,157 @@ impl TryFrom<char> for Cell {
             'F' => Ok(Cell::SE),
             '.' => Ok(Cell::Ground),
             'S' => Ok(Cell::Start),
-            other => Err(eyre!("invalid pipe {}", other)),
+            other => Err(Error::invalid_char("pipe", other)),
         }
     }
 }
 
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Cell::NS => '|',
+            Cell::EW => '-',
+            Cell::NE => 'L',
+            Cell::NW => 'J',
+            Cell::SW => '7',
+            Cell::SE => 'F',
+            Cell::Ground => '.',
+            Cell::Start => 'S',
+        };
+        write!(f, "{}", c)
+    }
+}
+
 pub mod parser {
     use aoc::parser::*;
 
     use super::*;
 
-    fn cell(input: &str) -> IResult<&str, Cell> {
-        let (input, c) = character::one_of("|-LJ7F.S")(input)?;
-        Ok((input, Cell::try_from(c).unwrap()))
-    }
-
-    fn line(input: &str) -> IResult<&str, Vec<Cell>> {
-        let (input, cells) = multi::many1(cell)(input)?;
-        let (input, _) = character::newline(input)?;
-        Ok((input, cells))
+    fn grid_start(input: &str) -> IResult<&str, (Vec<Vec<Cell>>, Pos)> {
+        let (rest, (rows, starts)) = char_grid_marked(Cell::Start)(input)?;
+        match starts[..] {
+            [start] => Ok((rest, (rows, start))),
+            _ => fail(input, format!("expected one start, found {}", starts.len())),
+        }
     }
 
-    pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
-        aoc::parse_with!(multi::many1(line), bufin)
+    pub fn parse(mut bufin: impl BufRead) -> Result<(Vec<Vec<Cell>>, Pos)> {
+        aoc::parse_with!(grid_start, bufin)
     }
 }
 
 #[test]
 fn test() -> Result<()> {
-    assert_eq!(parser::parse(EXAMPLE1.as_bytes())?.len(), 5);
-    assert_eq!(parser::parse(EXAMPLE1.as_bytes())?[0].len(), 5);
-    assert_eq!(parser::parse(EXAMPLE2.as_bytes())?.len(), 5);
-    assert_eq!(parser::parse(EXAMPLE2.as_bytes())?[0].len(), 5);
+    assert_eq!(parser::parse(EXAMPLE1.as_bytes())?.0.len(), 5);
+    assert_eq!(parser::parse(EXAMPLE1.as_bytes())?.0[0].len(), 5);
+    assert_eq!(parser::parse(EXAMPLE1.as_bytes())?.1, Pos::new(1, 1));
+    assert_eq!(parser::parse(EXAMPLE2.as_bytes())?.0.len(), 5);
+    assert_eq!(parser::parse(EXAMPLE2.as_bytes())?.0[0].len(), 5);
+    assert_eq!(parser::parse(EXAMPLE2.as_bytes())?.1, Pos::new(0, 2));
     Ok(())
 }
+
+#[test]
+fn test_open_loop() -> Result<()> {
+    let input = parser::parse(".S-7.\n.|.|.\n.L-..\n".as_bytes())?;
+    for part in Part::ALL {
+        let e = Day10.solve(part, input.clone()).unwrap_err();
+        let expected = Error::no_solution("no loop through start");
+        assert_eq!(e.downcast_ref(), Some(&expected));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u32 = 10;
+    type Input = (Vec<Vec<Cell>>, Pos);
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::char_grid(&input.0)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day10a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day10b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day10;
+    A: EXAMPLE1 => 4;
+    A: EXAMPLE2 => 8;
+    B: EXAMPLE3 => 4;
+    B: EXAMPLE4 => 10;
+}
+
+impl prop::Generate for Day10 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let (width, height) = (g.len(2), g.len(2));
+        let pipes = [Cell::NS, Cell::EW, Cell::NE, Cell::NW, Cell::SW, Cell::SE];
+        let mut rows = g.grid(width, height, |g| {
+            if g.bool() {
+                Cell::Ground
+            } else {
+                *g.choose(&pipes)
+            }
+        });
+        // The loop goes up the left column, right along the top,
+        // which has a random height at each column, down the right
+        // column and back left along the bottom row.
+        let x0 = g.range(0..=width - 2);
+        let x1 = g.range(x0 + 1..=width - 1);
+        let bottom = g.range(1..=height - 1);
+        let tops = g.vec(x1 - x0 + 1, |g| g.range(0..=bottom - 1));
+        let mut path = (tops[0]..=bottom)
+            .rev()
+            .map(|y| (x0, y))
+            .collect::<Vec<_>>();
+        for x in x0 + 1..=x1 {
+            let from = tops[x - x0 - 1];
+            let to = if x == x1 { bottom } else { tops[x - x0] };
+            if from <= to {
+                path.extend((from..=to).map(|y| (x, y)));
+            } else {
+                path.extend((to..=from).rev().map(|y| (x, y)));
+            }
+        }
+        path.extend((x0 + 1..x1).rev().map(|x| (x, bottom)));
+        let dir = |a: (usize, usize), b: (usize, usize)| match (
+            b.0 as i64 - a.0 as i64,
+            b.1 as i64 - a.1 as i64,
+        ) {
+            (0, -1) => Dir::N,
+            (1, 0) => Dir::E,
+            (0, 1) => Dir::S,
+            _ => Dir::W,
+        };
+        for (i, &pos) in path.iter().enumerate() {
+            let prev = path[(i + path.len() - 1) % path.len()];
+            let next = path[(i + 1) % path.len()];
+            let cell = match (dir(pos, prev), dir(pos, next)) {
+                (Dir::N, Dir::S) | (Dir::S, Dir::N) => Cell::NS,
+                (Dir::E, Dir::W) | (Dir::W, Dir::E) => Cell::EW,
+                (Dir::N, Dir::E) | (Dir::E, Dir::N) => Cell::NE,
+                (Dir::N, Dir::W) | (Dir::W, Dir::N) => Cell::NW,
+                (Dir::S, Dir::W) | (Dir::W, Dir::S) => Cell::SW,
+                _ => Cell::SE,
+            };
+            rows[pos.1][pos.0] = cell;
+        }
+        let start = *g.choose(&path);
+        rows[start.1][start.0] = Cell::Start;
+        (rows, Pos::new(start.0, start.1))
+    }
+}
+
+aoc::properties!(Day10);
@@ -0,0 +1,9 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(inc: i64, input: Vec<Vec<Cell>>) -> Result<i64> {
+    calc_distances(inc, input)
+}
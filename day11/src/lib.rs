@@ -5,6 +5,12 @@
 pub use aoc::*;
 
 use std::collections::HashSet;
+use std::fmt;
+
+use aoc::geom::Point2;
+
+pub mod day11a;
+pub mod day11b;
 
 pub const EXAMPLE: &str = "...#......
 .......#..
@@ -26,12 +32,21 @@ pub enum Cell {
 }
 
 impl TryFrom<char> for Cell {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '#' => Ok(Cell::Galaxy),
             '.' => Ok(Cell::Empty),
-            other => Err(eyre!("invalid cell {}", other)),
+            other => Err(Error::invalid_char("cell", other)),
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Galaxy => write!(f, "#"),
+            Cell::Empty => write!(f, "."),
         }
     }
 }
@@ -41,19 +56,8 @@ pub mod parser {
 
     use super::*;
 
-    fn cell(input: &str) -> IResult<&str, Cell> {
-        let (input, c) = character::one_of(".#")(input)?;
-        Ok((input, Cell::try_from(c).unwrap()))
-    }
-
-    fn line(input: &str) -> IResult<&str, Vec<Cell>> {
-        let (input, cells) = multi::many1(cell)(input)?;
-        let (input, _) = character::newline(input)?;
-        Ok((input, cells))
-    }
-
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
-        aoc::parse_with!(multi::many1(line), bufin)
+        aoc::parse_with!(char_grid, bufin)
     }
 }
 
@@ -70,26 +74,18 @@ pub fn calc_distances(inc: i64, input: Vec<Vec<Cell>>) -> Result<i64> {
         .flat_map(|(y, line)| {
             line.into_iter().enumerate().filter_map(move |(x, cell)| {
                 if cell == Cell::Galaxy {
-                    Some((x as i64, y as i64))
+                    Some(Point2::new(x as i64, y as i64))
                 } else {
                     None
                 }
             })
         })
-        .collect::<HashSet<(i64, i64)>>();
-    let xs = galaxies
-        .iter()
-        .map(|(x, _)| x)
-        .copied()
-        .collect::<HashSet<_>>();
+        .collect::<HashSet<Point2>>();
+    let xs = galaxies.iter().map(|g| g.x).collect::<HashSet<_>>();
     let xmax = xs.iter().max().copied().unwrap();
-    let ys = galaxies
-        .iter()
-        .map(|(_, y)| y)
-        .copied()
-        .collect::<HashSet<_>>();
+    let ys = galaxies.iter().map(|g| g.y).collect::<HashSet<_>>();
     let ymax = ys.iter().max().copied().unwrap();
-    let mut galaxies2 = Vec::<(i64, i64)>::new();
+    let mut galaxies2 = Vec::<Point2>::new();
     let mut yinc = 0_i64;
     for y in 0..=ymax {
         if !ys.contains(&y) {
@@ -99,8 +95,8 @@ pub fn calc_distances(inc: i64, input: Vec<Vec<Cell>>) -> Result<i64> {
             for x in 0..=xmax {
                 if !xs.contains(&x) {
                     xinc += inc;
-                } else if galaxies.contains(&(x, y)) {
-                    galaxies2.push((x + xinc, y + yinc));
+                } else if galaxies.contains(&Point2::new(x, y)) {
+                    galaxies2.push(Point2::new(x + xinc, y + yinc));
                 }
             }
         }
@@ -110,8 +106,62 @@ pub fn calc_distances(inc: i64, input: Vec<Vec<Cell>>) -> Result<i64> {
         .enumerate()
         .flat_map(|(i, g1)| galaxies2[i + 1..].iter().map(move |g2| (g1, g2)))
         .collect::<Vec<_>>();
-    Ok(gpairs
-        .into_iter()
-        .map(|(g1, g2)| (g2.0 - g1.0).abs() + (g2.1 - g1.1).abs())
-        .sum())
+    Ok(gpairs.into_iter().map(|(g1, g2)| g1.manhattan(g2)).sum())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day11 {
+    pub inc: i64,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 { inc: 999_999 }
+    }
 }
+
+impl Solver for Day11 {
+    const DAY: u32 = 11;
+    type Input = Vec<Vec<Cell>>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::char_grid(input)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day11a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day11b::process(self.inc, input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day11;
+    A: EXAMPLE => 374;
+    B(Day11 { inc: 9 }): EXAMPLE => 1030;
+    B(Day11 { inc: 99 }): EXAMPLE => 8410;
+}
+
+impl prop::Generate for Day11 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let (width, height) = (g.len(1), g.len(1));
+        let mut rows = g.grid(width, height, |g| {
+            if g.one_in(5) {
+                Cell::Galaxy
+            } else {
+                Cell::Empty
+            }
+        });
+        let (x, y) = (g.range(0..=width - 1), g.range(0..=height - 1));
+        rows[y][x] = Cell::Galaxy;
+        rows
+    }
+}
+
+aoc::properties!(Day11);
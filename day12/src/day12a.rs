@@ -0,0 +1,12 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<(Row, Vec<u32>)>) -> Result<usize> {
+    Ok(input
+        .into_iter()
+        .map(|entry| calc_arrangements(&entry.0, &entry.1))
+        .sum())
+}
@@ -0,0 +1,23 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn unfold_calc_arrangements(row: &Row, record: &[u32]) -> usize {
+    let mut bigrow = row.clone();
+    let mut bigrecord = record.to_vec();
+    for _ in 0..4 {
+        bigrow.0.push(Cell::Unknown);
+        bigrow.0.extend(row.0.iter());
+        bigrecord.extend(record.iter());
+    }
+    calc_arrangements(&bigrow, &bigrecord)
+}
+
+pub fn process(input: Vec<(Row, Vec<u32>)>) -> Result<usize> {
+    Ok(input
+        .into_iter()
+        .map(|entry| unfold_calc_arrangements(&entry.0, &entry.1))
+        .sum())
+}
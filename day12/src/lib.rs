@@ -7,6 +7,9 @@ pub use aoc::*;
 use std::fmt;
 use std::str::FromStr;
 
+pub mod day12a;
+pub mod day12b;
+
 pub const EXAMPLE: &str = "???.### 1,1,3
 .??..??...?##. 1,1,3
 ?#?#?#?#?#?#?#? 1,3,1,6
@@ -33,13 +36,13 @@ impl fmt::Display for Cell {
 }
 
 impl TryFrom<char> for Cell {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '.' => Ok(Cell::Ok),
             '#' => Ok(Cell::Broken),
             '?' => Ok(Cell::Unknown),
-            other => Err(eyre!("invalid spring {}", other)),
+            other => Err(Error::invalid_char("spring", other)),
         }
     }
 }
@@ -47,12 +50,6 @@ impl TryFrom<char> for Cell {
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct Row(pub Vec<Cell>);
 
-impl Row {
-    pub fn subrow(&self, from: usize) -> Row {
-        Row(self.0[from..].to_vec())
-    }
-}
-
 impl fmt::Display for Row {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for cell in &self.0 {
@@ -68,7 +65,7 @@ impl FromStr for Row {
         Ok(Row(s
             .chars()
             .map(Cell::try_from)
-            .collect::<Result<Vec<Cell>>>()?))
+            .collect::<Result<Vec<Cell>, _>>()?))
     }
 }
 
@@ -101,7 +98,10 @@ fn test() -> Result<()> {
     Ok(())
 }
 
-pub type Cache = std::collections::HashMap<(Row, Vec<u32>), usize>;
+/// Arrangements of the rest of the row, keyed by the position of an
+/// unknown cell and by the remaining record, which is a suffix of the
+/// original one with the first run possibly shortened
+pub type Cache = memo::Memo<(usize, usize, Option<u32>), usize>;
 
 pub fn helper(
     cache: &mut Cache,
@@ -115,10 +115,6 @@ pub fn helper(
         let success = record.is_empty() || record == vec![0];
         return if success { 1 } else { 0 };
     }
-    let key = (row.subrow(irow), record.clone());
-    if let Some(result) = cache.get(&key) {
-        return *result;
-    }
     match row.0[irow] {
         Cell::Ok => {
             let mut newrecord = record.clone();
@@ -141,19 +137,21 @@ pub fn helper(
             helper(cache, row, irow + 1, newrecord, currun + 1, false)
         }
         Cell::Unknown => {
-            row.0[irow] = Cell::Ok;
-            let newrecord1 = record.clone();
-            let oksum = helper(cache, row, irow, newrecord1, currun, _lastok);
-            let brksum = if !record.is_empty() || record != vec![0] {
-                row.0[irow] = Cell::Broken;
-                let newrecord2 = record.clone();
-                helper(cache, row, irow, newrecord2, currun, _lastok)
-            } else {
-                0
-            };
-            row.0[irow] = Cell::Unknown;
-            cache.insert(key, oksum + brksum);
-            oksum + brksum
+            let key = (irow, record.len(), record.first().copied());
+            cache.get_or_insert_with(key, |cache| {
+                row.0[irow] = Cell::Ok;
+                let newrecord1 = record.clone();
+                let oksum = helper(cache, row, irow, newrecord1, currun, _lastok);
+                let brksum = if !record.is_empty() || record != vec![0] {
+                    row.0[irow] = Cell::Broken;
+                    let newrecord2 = record.clone();
+                    helper(cache, row, irow, newrecord2, currun, _lastok)
+                } else {
+                    0
+                };
+                row.0[irow] = Cell::Unknown;
+                oksum + brksum
+            })
         }
     }
 }
@@ -161,7 +159,7 @@ pub fn helper(
 pub fn calc_arrangements(row: &Row, record: &[u32]) -> usize {
     let mut mrow = row.clone();
     let rec = record.to_vec();
-    let mut cache = Cache::new();
+    let mut cache = Cache::new("day12 arrangements");
     helper(&mut cache, &mut mrow, 0, rec, 0, true)
 }
 
@@ -213,3 +211,67 @@ fn test_calc_arrangements_6() -> Result<()> {
     assert_eq!(calc_arrangements(&row, &record), 10);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u32 = 12;
+    type Input = Vec<(Row, Vec<u32>)>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(
+            input
+                .iter()
+                .map(|(row, runs)| format!("{} {}", row, unparse::join(runs, ","))),
+        )
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day12a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day12b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day12;
+    A: EXAMPLE => 21;
+    B: EXAMPLE => 525152;
+}
+
+impl prop::Generate for Day12 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let len = g.len(1);
+        g.vec(len, |g| {
+            // Runs come from the actual springs, some of which are
+            // then hidden
+            let width = g.len(1);
+            let mut springs = g.vec(width, |g| g.bool());
+            let broken = g.range(0..=width - 1);
+            springs[broken] = true;
+            let runs = springs
+                .split(|broken| !broken)
+                .filter(|run| !run.is_empty())
+                .map(|run| run.len() as u32)
+                .collect();
+            let row = springs
+                .into_iter()
+                .map(|broken| match (g.bool(), broken) {
+                    (true, _) => Cell::Unknown,
+                    (false, true) => Cell::Broken,
+                    (false, false) => Cell::Ok,
+                })
+                .collect();
+            (Row(row), runs)
+        })
+    }
+}
+
+aoc::properties!(Day12);
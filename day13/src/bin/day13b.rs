@@ -4,43 +4,6 @@
 
 use day13::*;
 
-use std::collections::HashSet;
-
-pub fn flip(g: &mut HashSet<Xy>, xy: &Xy) {
-    if g.contains(xy) {
-        g.remove(xy);
-    } else {
-        g.insert(*xy);
-    }
-}
-
-fn calc_summary(vecgrid: VecGrid) -> usize {
-    let (mut grid, size) = vecgrid2hashset(&vecgrid);
-    let old = find_mirror_summary(&grid, &size, None);
-    for y_smudge in 0..size.1 {
-        for x_smudge in 0..size.0 {
-            let smudge = (x_smudge, y_smudge);
-            flip(&mut grid, &smudge);
-            if let Some(summary) = find_mirror_summary(&grid, &size, old) {
-                return summary;
-            }
-            flip(&mut grid, &smudge);
-        }
-    }
-    panic!("no mirror found");
-}
-
-fn process(bufin: impl BufRead) -> Result<usize> {
-    let input = parser::parse(bufin)?;
-    Ok(input.into_iter().map(calc_summary).sum())
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, 400);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(&Day13, Part::B)
 }
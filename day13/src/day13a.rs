@@ -0,0 +1,15 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+fn calc_summary(vecgrid: VecGrid) -> Result<usize> {
+    let (grid, size) = vecgrid2hashset(&vecgrid);
+    find_mirror_summary(&grid, &size, None)
+        .ok_or_else(|| Error::no_solution("no mirror found").into())
+}
+
+pub fn process(input: Vec<VecGrid>) -> Result<usize> {
+    input.into_iter().map(calc_summary).sum::<Result<_>>()
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashSet;
+
+pub fn flip(g: &mut HashSet<Xy>, xy: &Xy) {
+    if g.contains(xy) {
+        g.remove(xy);
+    } else {
+        g.insert(*xy);
+    }
+}
+
+fn calc_summary(vecgrid: VecGrid) -> Result<usize> {
+    let (mut grid, size) = vecgrid2hashset(&vecgrid);
+    let old = find_mirror_summary(&grid, &size, None);
+    for y_smudge in 0..size.1 {
+        for x_smudge in 0..size.0 {
+            let smudge = (x_smudge, y_smudge);
+            flip(&mut grid, &smudge);
+            if let Some(summary) = find_mirror_summary(&grid, &size, old) {
+                log::debug!("smudge at {:?}, summary {}", smudge, summary);
+                log_grid(&grid, size);
+                return Ok(summary);
+            }
+            flip(&mut grid, &smudge);
+        }
+    }
+    Err(Error::no_solution("no mirror found").into())
+}
+
+pub fn process(input: Vec<VecGrid>) -> Result<usize> {
+    input.into_iter().map(calc_summary).sum::<Result<_>>()
+}
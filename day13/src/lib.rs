@@ -6,6 +6,9 @@ pub use aoc::*;
 
 use std::collections::HashSet;
 
+pub mod day13a;
+pub mod day13b;
+
 pub const EXAMPLE: &str = "#.##..##.
 ..#.##.#.
 ##......#
@@ -63,6 +66,19 @@ fn test() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_no_mirror() -> Result<()> {
+    let input = parser::parse("#.\n.#\n".as_bytes())?;
+    for part in Part::ALL {
+        let e = Day13.solve(part, input.clone()).unwrap_err();
+        assert_eq!(
+            e.downcast_ref(),
+            Some(&Error::no_solution("no mirror found"))
+        );
+    }
+    Ok(())
+}
+
 pub fn vecgrid2hashset(vecgrid: &VecGrid) -> (HashSet<Xy>, Xy) {
     let size = (vecgrid[0].len(), vecgrid.len());
     (
@@ -78,7 +94,7 @@ pub fn translate(g: &HashSet<Xy>) -> HashSet<Xy> {
 }
 
 fn gridmatch(mirror: usize, size: &Xy, g: &HashSet<Xy>) -> bool {
-    let xmin = if 2 * mirror >= size.0 + 1 {
+    let xmin = if 2 * mirror > size.0 {
         2 * mirror - size.0 + 2
     } else {
         0
@@ -114,12 +130,95 @@ pub fn find_mirror_summary(grid: &HashSet<Xy>, size: &Xy, old: Option<usize>) ->
     None
 }
 
-pub fn print_grid(grid: &HashSet<Xy>, size: Xy) {
+/// Logs the grid at debug level
+pub fn log_grid(grid: &HashSet<Xy>, size: Xy) {
+    if !log::log_enabled!(log::Level::Debug) {
+        return;
+    }
+    let mut text = String::new();
     for y in 0..size.1 {
         for x in 0..size.0 {
             let xy = (x, y);
-            eprint!("{}", if grid.contains(&xy) { '#' } else { '.' });
+            text.push(if grid.contains(&xy) { '#' } else { '.' });
         }
-        eprintln!();
+        text.push('\n');
     }
+    log::debug!("grid:\n{}", text);
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u32 = 13;
+    type Input = Vec<VecGrid>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        let grids = input.iter().map(|grid| {
+            unparse::lines(grid.iter().map(|row| {
+                row.iter()
+                    .map(|&c| if c { '#' } else { '.' })
+                    .collect::<String>()
+            }))
+        });
+        unparse::join(grids, "\n")
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day13a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day13b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day13;
+    A: EXAMPLE => 405;
+    B: EXAMPLE => 400;
+}
+
+impl prop::Generate for Day13 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let len = g.range(1..=1 + g.size() / 4);
+        g.vec(len, |g| {
+            // Horizontal mirror after row r, that leaves rows below
+            // it uncovered, and vertical mirror after column c, that
+            // has a smudge in one of those rows.
+            let (width, height) = (g.len(2), g.len(3));
+            let r = g.range(0..=(height - 1) / 2 - 1);
+            let c = g.range(0..=width - 2);
+            let mut grid = g.grid(width, height, |g| g.bool());
+            for row in grid.iter_mut() {
+                for x in c + 1..width.min(2 * c + 2) {
+                    row[x] = row[2 * c + 1 - x];
+                }
+            }
+            for y in r + 1..=2 * r + 1 {
+                grid[y] = grid[2 * r + 1 - y].clone();
+            }
+            let y = g.range(2 * r + 2..=height - 1);
+            let x = g.range((2 * c + 2).saturating_sub(width)..=width.min(2 * c + 2) - 1);
+            grid[y][x] = !grid[y][x];
+            if g.bool() {
+                grid.reverse();
+            }
+            if g.bool() {
+                grid.iter_mut().for_each(|row| row.reverse());
+            }
+            if g.bool() {
+                grid = (0..width)
+                    .map(|x| grid.iter().map(|row| row[x]).collect())
+                    .collect();
+            }
+            grid
+        })
+    }
+}
+
+aoc::properties!(Day13);
@@ -0,0 +1,11 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<Vec<Cell>>) -> Result<usize> {
+    let mut grid = Grid::try_from(input)?;
+    grid = tilt(grid, Dir::N);
+    Ok(grid_load(&grid))
+}
@@ -0,0 +1,26 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+const CYCLES: usize = 1000000000;
+
+/// Tilts the platform in each direction, animating each tilt
+pub fn spin(grid: &Grid, animation: &mut anim::Animation) -> Grid {
+    [Dir::N, Dir::W, Dir::S, Dir::E]
+        .into_iter()
+        .fold(grid.clone(), |grid, dir| {
+            let grid = tilt(grid, dir);
+            animation.frame(|| grid_image(&grid));
+            grid
+        })
+}
+
+pub fn process(input: Vec<Vec<Cell>>) -> Result<usize> {
+    let grid = Grid::try_from(input)?;
+    let mut animation = anim::Animation::from_env("day14b")?;
+    let grid = cycle::nth(grid, |grid| spin(grid, &mut animation), CYCLES);
+    animation.finish()?;
+    Ok(grid_load(&grid))
+}
@@ -4,10 +4,11 @@
 
 pub use aoc::*;
 
-use sqrid::postrait::PosT;
-
 use std::fmt;
 
+pub mod day14a;
+pub mod day14b;
+
 pub const EXAMPLE: &str = "O....#....
 O.OO#....#
 .....##...
@@ -29,21 +30,20 @@ pub enum Cell {
 }
 
 impl TryFrom<char> for Cell {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '.' => Ok(Cell::Empty),
             '#' => Ok(Cell::Wall),
             'O' => Ok(Cell::Rock),
-            other => Err(eyre!("invalid cell {}", other)),
+            other => Err(Error::invalid_char("cell", other)),
         }
     }
 }
 
+pub use aoc::grid::Pos;
 pub use sqrid::Dir;
-pub type Sqrid = sqrid::sqrid_create!(100, 100, false);
-pub type Pos = sqrid::pos_create!(Sqrid);
-pub type Grid = sqrid::grid_create!(Sqrid, Cell);
+pub type Grid = aoc::grid::Grid<Cell>;
 
 impl fmt::Display for Cell {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -60,19 +60,8 @@ pub mod parser {
 
     use super::*;
 
-    fn cell(input: &str) -> IResult<&str, Cell> {
-        let (input, c) = character::one_of(".#O")(input)?;
-        Ok((input, Cell::try_from(c).unwrap()))
-    }
-
-    fn line(input: &str) -> IResult<&str, Vec<Cell>> {
-        let (input, cells) = multi::many1(cell)(input)?;
-        let (input, _) = character::newline(input)?;
-        Ok((input, cells))
-    }
-
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
-        aoc::parse_with!(multi::many1(line), bufin)
+        aoc::parse_with!(char_grid, bufin)
     }
 }
 
@@ -84,21 +73,19 @@ fn test() -> Result<()> {
     Ok(())
 }
 
-pub fn tilt(size: usize, mut grid: Grid, dir: Dir) -> Grid {
+pub fn tilt(mut grid: Grid, dir: Dir) -> Grid {
     let qas = if dir == Dir::N || dir == Dir::W {
-        Pos::iter().collect::<Vec<_>>()
+        grid.iter_pos().collect::<Vec<_>>()
     } else {
-        Pos::iter().rev().collect::<Vec<_>>()
+        grid.iter_pos().rev().collect::<Vec<_>>()
     };
     for qa_rock in qas {
         if grid[qa_rock] != Cell::Rock {
             continue;
         }
         let mut pos = qa_rock;
-        while let Ok(qa_new) = pos + dir {
-            let t = qa_new.tuple();
-            let t = (t.0 as usize, t.1 as usize);
-            if t.0 >= size || t.1 >= size || grid[qa_new] != Cell::Empty {
+        while let Some(qa_new) = grid.go(pos, dir) {
+            if grid[qa_new] != Cell::Empty {
                 break;
             }
             pos = qa_new;
@@ -111,15 +98,63 @@ pub fn tilt(size: usize, mut grid: Grid, dir: Dir) -> Grid {
     grid
 }
 
-pub fn grid_load(size: usize, grid: &Grid) -> usize {
-    Pos::iter()
+pub fn grid_load(grid: &Grid) -> usize {
+    grid.iter_pos()
         .map(|pos| {
             if grid[pos] == Cell::Rock {
-                let t = pos.tuple();
-                size - t.1 as usize
+                grid.height() - pos.y
             } else {
                 0
             }
         })
         .sum()
 }
+
+pub fn grid_image(grid: &Grid) -> render::Image {
+    render::Image::from_grid(grid, |cell| match cell {
+        Cell::Empty => render::Rgb::DARK,
+        Cell::Wall => render::Rgb::GRAY,
+        Cell::Rock => render::Rgb::YELLOW,
+    })
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u32 = 14;
+    type Input = Vec<Vec<Cell>>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::char_grid(input)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day14a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day14b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day14;
+    A: EXAMPLE => 136;
+    B: EXAMPLE => 64;
+}
+
+impl prop::Generate for Day14 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let (width, height) = (g.len(1), g.len(1));
+        g.grid(width, height, |g| {
+            *g.choose(&[Cell::Empty, Cell::Empty, Cell::Wall, Cell::Rock])
+        })
+    }
+}
+
+aoc::properties!(Day14);
@@ -0,0 +1,18 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+fn hash(steps: &Step) -> usize {
+    hash_str(&format!("{}", steps))
+}
+
+#[test]
+fn test_hash() {
+    assert_eq!(hash_str("HASH"), 52);
+}
+
+pub fn process(input: Vec<Step>) -> Result<usize> {
+    Ok(input.into_iter().map(|step| hash(&step)).sum())
+}
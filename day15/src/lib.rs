@@ -6,6 +6,9 @@ pub use aoc::*;
 
 pub use std::fmt;
 
+pub mod day15a;
+pub mod day15b;
+
 pub const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
 
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
@@ -17,6 +20,7 @@ impl fmt::Display for Label {
     }
 }
 
+#[derive(PartialEq, Eq, Clone, Debug)]
 pub enum Op {
     Del,
     Focus(u32),
@@ -31,6 +35,7 @@ impl fmt::Display for Op {
     }
 }
 
+#[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Step {
     pub label: Label,
     pub op: Op,
@@ -98,3 +103,54 @@ pub fn hash_str(s: &str) -> usize {
         ((value + ascii) * 17) % 256
     })
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: u32 = 15;
+    type Input = Vec<Step>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::join(input, ",") + "\n"
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day15a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day15b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day15;
+    A: EXAMPLE => 1320;
+    B: EXAMPLE => 145;
+}
+
+impl prop::Generate for Day15 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let labels = g.len(1);
+        let labels = g.vec(labels, |g| {
+            let len = g.range(1..=4);
+            Label(g.string(len, "abcdefghijklmnopqrstuvwxyz"))
+        });
+        let len = g.len(1);
+        g.vec(len, |g| {
+            let op = if g.one_in(3) {
+                Op::Del
+            } else {
+                Op::Focus(g.range(1..=9))
+            };
+            Step::new(g.choose(&labels).clone(), op)
+        })
+    }
+}
+
+aoc::properties!(Day15);
@@ -0,0 +1,36 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashSet;
+
+pub fn process(input: Vec<Vec<Cell>>) -> Result<usize> {
+    let grid = Grid::try_from(input)?;
+    let size = grid.width().max(grid.height());
+    let mut beams = Beams([(Pos::TOP_LEFT, Dir::E)].into_iter().collect());
+    beams.process(&grid);
+    let mut energized = [Pos::TOP_LEFT].into_iter().collect::<HashSet<Pos>>();
+    let mut unchanged = 0;
+    let mut animation = anim::Animation::from_env("day16a")?;
+    while !beams.0.is_empty() && unchanged < size {
+        let old = energized.clone();
+        beams.next(&grid);
+        energized.extend(beams.0.iter().map(|(pos, _)| pos));
+        animation.frame(|| {
+            let beams = beams.0.iter().map(|(pos, _)| *pos);
+            contraption_image(&grid, |pos| energized.contains(pos), beams)
+        });
+        if energized != old {
+            unchanged = 0;
+        } else {
+            unchanged += 1;
+        }
+    }
+    animation.finish()?;
+    render::dump("day16a", || {
+        contraption_image(&grid, |pos| energized.contains(pos), [])
+    })?;
+    Ok(energized.len())
+}
@@ -0,0 +1,20 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<Vec<Cell>>) -> Result<usize> {
+    let grid = Grid::try_from(input)?;
+    let mut cache = Cache::new("day16 beams");
+    let br = grid.bottom_right();
+    let vertical =
+        (0..grid.width()).flat_map(|x| [(Pos::new(x, 0), Dir::S), (Pos::new(x, br.y), Dir::N)]);
+    let horizontal =
+        (0..grid.height()).flat_map(|y| [(Pos::new(0, y), Dir::E), (Pos::new(br.x, y), Dir::W)]);
+    vertical
+        .chain(horizontal)
+        .map(|start| calc_energized(&grid, &mut cache, start))
+        .max()
+        .ok_or_else(|| Error::no_solution("no beam entry points").into())
+}
@@ -5,9 +5,11 @@
 pub use aoc::*;
 
 use std::collections::BTreeSet;
-use std::collections::HashMap;
 use std::fmt;
 
+pub mod day16a;
+pub mod day16b;
+
 pub const EXAMPLE: &str = r".|...\....
 |.-.\.....
 .....|-...
@@ -31,7 +33,7 @@ pub enum Cell {
 }
 
 impl TryFrom<char> for Cell {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '.' => Ok(Cell::Empty),
@@ -39,7 +41,19 @@ impl TryFrom<char> for Cell {
             '/' => Ok(Cell::MirrorD),
             '-' => Ok(Cell::SplitH),
             '|' => Ok(Cell::SplitV),
-            other => Err(eyre!("invalid pipe {}", other)),
+            other => Err(Error::invalid_char("pipe", other)),
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Empty => write!(f, "."),
+            Cell::MirrorU => write!(f, "\\"),
+            Cell::MirrorD => write!(f, "/"),
+            Cell::SplitH => write!(f, "-"),
+            Cell::SplitV => write!(f, "|"),
         }
     }
 }
@@ -49,19 +63,8 @@ pub mod parser {
 
     use super::*;
 
-    fn cell(input: &str) -> IResult<&str, Cell> {
-        let (input, c) = character::one_of(r".\/-|")(input)?;
-        Ok((input, Cell::try_from(c).unwrap()))
-    }
-
-    fn line(input: &str) -> IResult<&str, Vec<Cell>> {
-        let (input, cells) = multi::many1(cell)(input)?;
-        let (input, _) = character::newline(input)?;
-        Ok((input, cells))
-    }
-
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
-        aoc::parse_with!(multi::many1(line), bufin)
+        aoc::parse_with!(char_grid, bufin)
     }
 }
 
@@ -73,10 +76,9 @@ fn test() -> Result<()> {
     Ok(())
 }
 
+pub use aoc::grid::Pos;
 pub use sqrid::Dir;
-pub type Sqrid = sqrid::sqrid_create!(110, 110, false);
-pub type Pos = sqrid::pos_create!(Sqrid);
-pub type Grid = sqrid::grid_create!(Sqrid, Cell);
+pub type Grid = aoc::grid::Grid<Cell>;
 
 pub type Beam = (Pos, Dir);
 
@@ -84,15 +86,11 @@ pub type Beam = (Pos, Dir);
 pub struct Beams(pub BTreeSet<Beam>);
 
 impl Beams {
-    pub fn next(&mut self, size: u16, grid: &Grid) {
+    pub fn next(&mut self, grid: &Grid) {
         let beams = std::mem::take(&mut self.0);
         for beam in beams {
             let (pos, dir) = beam;
-            if let Ok(newpos) = pos + dir {
-                let t = newpos.tuple();
-                if t.0 >= size || t.1 >= size {
-                    continue;
-                }
+            if let Some(newpos) = grid.go(pos, dir) {
                 self.0.insert((newpos, dir));
             }
         }
@@ -158,17 +156,38 @@ impl fmt::Display for Beams {
             if i > 0 {
                 write!(f, ", ")?;
             }
-            let t = b.0.tuple();
-            write!(f, "({},{};{})", t.0, t.1, b.1)?;
+            write!(f, "({},{};{})", b.0.x, b.0.y, b.1)?;
         }
         Ok(())
     }
 }
 
+/// Image of the contraption, with the energized tiles and the beams
+pub fn contraption_image(
+    grid: &Grid,
+    energized: impl Fn(&Pos) -> bool,
+    beams: impl IntoIterator<Item = Pos>,
+) -> render::Image {
+    let mut image = render::Image::new(grid.width(), grid.height(), render::Rgb::DARK);
+    for (pos, cell) in grid.iter() {
+        let colour = match (*cell, energized(&pos)) {
+            (Cell::Empty, false) => render::Rgb::DARK,
+            (_, false) => render::Rgb::GRAY,
+            (Cell::Empty, true) => render::Rgb::YELLOW,
+            (_, true) => render::Rgb::WHITE,
+        };
+        image.set(pos, colour);
+    }
+    image.overlay(beams, render::Rgb::RED);
+    image
+}
+
 pub type Energized = BTreeSet<Pos>;
-pub type Cache = HashMap<Beams, Energized>;
+/// Tiles energized from each state of the beams on
+pub type Cache = memo::Memo<Beams, Energized>;
 
-pub fn calc_energized(size: u16, grid: &Grid, cache: &mut Cache, start: Beam) -> usize {
+pub fn calc_energized(grid: &Grid, cache: &mut Cache, start: Beam) -> usize {
+    let size = grid.width().max(grid.height());
     let mut beams = Beams([start].into_iter().collect());
     beams.process(grid);
     let mut energized = Energized::default();
@@ -178,7 +197,7 @@ pub fn calc_energized(size: u16, grid: &Grid, cache: &mut Cache, start: Beam) ->
     let mut ecache = Energized::default();
     while !beams.0.is_empty() && unchanged < size {
         let old = energized.clone();
-        beams.next(size, grid);
+        beams.next(grid);
         if let Some(e) = cache.get(&beams) {
             energized.extend(e.iter());
             ecache = e.clone();
@@ -198,3 +217,49 @@ pub fn calc_energized(size: u16, grid: &Grid, cache: &mut Cache, start: Beam) ->
     }
     energized.len()
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY: u32 = 16;
+    type Input = Vec<Vec<Cell>>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::char_grid(input)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day16a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day16b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day16;
+    A: EXAMPLE => 46;
+    B: EXAMPLE => 51;
+}
+
+impl prop::Generate for Day16 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let (width, height) = (g.len(1), g.len(1));
+        let cells = [Cell::MirrorU, Cell::MirrorD, Cell::SplitH, Cell::SplitV];
+        g.grid(width, height, |g| {
+            if g.one_in(3) {
+                *g.choose(&cells)
+            } else {
+                Cell::Empty
+            }
+        })
+    }
+}
+
+aoc::properties!(Day16);
@@ -0,0 +1,12 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<Vec<Cell>>) -> Result<u32> {
+    let gheat = Grid::try_from(input)?;
+    solve("day17a", gheat, |st, dir| {
+        st.lastdir != Some(-dir) && (st.lastdir != Some(dir) || st.dircount < 3)
+    })
+}
@@ -0,0 +1,17 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<Vec<Cell>>) -> Result<u32> {
+    let gheat = Grid::try_from(input)?;
+    solve("day17b", gheat, |st, dir| {
+        // Can't go back:
+        st.lastdir != Some(-dir)
+            // Must go at least 4 spaces:
+            && (st.lastdir.is_none() || st.lastdir == Some(dir) || st.dircount >= 4)
+            // And not more than 10:
+            && (st.lastdir != Some(dir) || st.dircount < 10)
+    })
+}
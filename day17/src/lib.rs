@@ -4,12 +4,8 @@
 
 pub use aoc::*;
 
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
-use std::collections::HashMap;
-use std::collections::HashSet;
-
-use sqrid::postrait::PosT;
+pub mod day17a;
+pub mod day17b;
 
 pub const EXAMPLE: &str = "2413432311323
 3215453535623
@@ -57,27 +53,30 @@ fn test() -> Result<()> {
     Ok(())
 }
 
+pub use aoc::grid::Pos;
 pub use sqrid::Dir;
-pub type Sqrid = sqrid::sqrid_create!(141, 141, false);
-// pub type Sqrid = sqrid::sqrid_create!(13, 13, false);
-pub type Pos = sqrid::pos_create!(Sqrid);
-pub type Grid = sqrid::grid_create!(Sqrid, u32);
+pub type Grid = aoc::grid::Grid<u32>;
 
-pub type Griddir = sqrid::grid_create!(Sqrid, String);
+pub type Griddir = aoc::grid::Grid<String>;
 
-pub fn path_debug(_size: u16, gheat: &Grid, path: &[Dir]) {
-    let mut gheatacum = Grid::default();
+/// Logs the directions taken and the accumulated heat at debug level
+pub fn log_path(gheat: &Grid, path: &[Dir]) -> Result<()> {
+    if !log::log_enabled!(log::Level::Debug) {
+        return Ok(());
+    }
+    let mut gheatacum = Grid::new(gheat.width(), gheat.height())?;
     let mut pos = Pos::TOP_LEFT;
     let mut heat = 0;
-    let mut gdir = Griddir::default();
+    let mut gdir = Griddir::new(gheat.width(), gheat.height())?;
     for dir in path {
         gdir[pos] = dir.name_utf8().to_string();
-        pos = (pos + *dir).unwrap();
+        pos = gheat.go(pos, *dir).unwrap();
         heat += gheat[pos];
         gheatacum[pos] = heat;
     }
-    eprintln!("{:1}", gdir);
-    eprintln!("{:>4}", gheatacum);
+    log::debug!("path:\n{:1}", gdir);
+    log::debug!("heat:\n{:>4}", gheatacum);
+    Ok(())
 }
 
 pub type Heat = u32;
@@ -89,54 +88,79 @@ pub struct State {
     pub dircount: usize,
 }
 
-pub fn solve<F: Fn(&State, Dir) -> bool>(size: u16, gheat: Grid, dir_valid: F) -> Result<u32> {
-    let mut frontier = BinaryHeap::<(Reverse<Heat>, State)>::default();
-    frontier.push((Reverse(0), State::default()));
-    let mut visited = HashSet::<State>::default();
-    let mut heatacummap = HashMap::<State, Heat>::default();
-    heatacummap.insert(State::default(), 0);
-    let goal = Pos::try_from((size - 1, size - 1)).unwrap();
-    while let Some((_priority, st)) = frontier.pop() {
-        let pos = st.pos;
-        if visited.contains(&st) {
-            continue;
-        }
-        let heatacum = heatacummap[&st];
-        if pos == goal {
-            return Ok(heatacum);
-        }
-        for dir in Dir::iter::<false>() {
-            if !dir_valid(&st, dir) {
-                continue;
-            }
-            let Ok(newpos) = pos + dir else { continue };
-            let t = newpos.tuple();
-            if t.0 >= size || t.1 >= size {
-                continue;
-            }
-            let heatacum = heatacum + gheat[newpos];
-            let dircount = if Some(dir) == st.lastdir {
-                st.dircount + 1
-            } else {
-                1
-            };
-            let newst = State {
-                pos: newpos,
-                lastdir: Some(dir),
-                dircount,
-            };
-            let e = heatacummap.entry(newst).or_insert(heatacum);
-            if heatacum < *e {
-                *e = heatacum;
-            }
-            if visited.contains(&newst) {
-                continue;
-            }
-            let dist = Pos::manhattan(&newpos, &goal) as u32;
-            let priority = Reverse(heatacum + dist);
-            frontier.push((priority, newst));
-        }
-        visited.insert(st);
+/// Minimum heat loss; `name` identifies the rendered path
+pub fn solve<F: Fn(&State, Dir) -> bool>(name: &str, gheat: Grid, dir_valid: F) -> Result<u32> {
+    let goal = gheat.bottom_right();
+    let successors = |st: &State| {
+        Dir::iter::<false>()
+            .filter(|dir| dir_valid(st, *dir))
+            .filter_map(|dir| {
+                let newpos = gheat.go(st.pos, dir)?;
+                let dircount = if Some(dir) == st.lastdir {
+                    st.dircount + 1
+                } else {
+                    1
+                };
+                let newst = State {
+                    pos: newpos,
+                    lastdir: Some(dir),
+                    dircount,
+                };
+                Some((newst, gheat[newpos]))
+            })
+            .collect::<Vec<_>>()
+    };
+    let heuristic = |st: &State| Pos::manhattan(&st.pos, &goal) as Heat;
+    let (path, heat) =
+        graph::astar(State::default(), successors, heuristic, |st| st.pos == goal)
+            .ok_or_else(|| Error::no_solution(format!("goal {} is not reachable", goal)))?;
+    let dirs = path.iter().filter_map(|st| st.lastdir).collect::<Vec<_>>();
+    log_path(&gheat, &dirs)?;
+    render::dump(name, || {
+        let mut image = render::Image::from_grid(&gheat, |&heat| {
+            render::Rgb::DARK.blend(render::Rgb::RED, heat as f64 / 9.0)
+        });
+        image.path(path.iter().map(|st| st.pos), render::Rgb::YELLOW);
+        image
+    })?;
+    Ok(heat)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY: u32 = 17;
+    type Input = Vec<Vec<Cell>>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
     }
-    unreachable!();
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::char_grid(input)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day17a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day17b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day17;
+    A: EXAMPLE => 102;
+    B: EXAMPLE => 94;
 }
+
+impl prop::Generate for Day17 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let (width, height) = (g.len(1), g.len(1));
+        g.grid(width, height, |g| g.range(1..=9))
+    }
+}
+
+aoc::properties!(Day17);
@@ -4,20 +4,6 @@
 
 use day18::*;
 
-fn process(bufin: impl BufRead) -> Result<i64> {
-    let instructions = parser::parse(bufin)?
-        .into_iter()
-        .map(|(dir, meters, _)| (dir, meters))
-        .collect::<Vec<_>>();
-    calc_area(instructions)
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, 62);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(&Day18, Part::A)
 }
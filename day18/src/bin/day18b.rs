@@ -4,21 +4,6 @@
 
 use day18::*;
 
-fn process(bufin: impl BufRead) -> Result<i64> {
-    let input = parser::parse(bufin)?;
-    let instructions = input
-        .into_iter()
-        .map(|(_, _, color)| color2instr(color))
-        .collect::<Result<Vec<_>>>()?;
-    calc_area(instructions)
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, 952408144115);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(&Day18, Part::B)
 }
@@ -0,0 +1,13 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(input: Vec<(Dir, i64, u32)>) -> Result<i64> {
+    let instructions = input
+        .into_iter()
+        .map(|(dir, meters, _)| (dir, meters))
+        .collect::<Vec<_>>();
+    calc_area(instructions)
+}
@@ -4,6 +4,9 @@
 
 pub use aoc::*;
 
+pub mod day18a;
+pub mod day18b;
+
 pub const EXAMPLE: &str = "R 6 (#70c710)
 D 5 (#0dc571)
 L 2 (#5713f0)
@@ -20,6 +23,7 @@ L 2 (#015232)
 U 2 (#7a21e3)
 ";
 
+pub use aoc::geom::Point2;
 pub use sqrid::Dir;
 
 pub mod parser {
@@ -74,8 +78,6 @@ fn test() -> Result<()> {
     Ok(())
 }
 
-pub type Point = (i64, i64);
-
 pub fn color2instr(color: u32) -> Result<(Dir, i64)> {
     let colorstr = format!("{:x}", color);
     let mut chars = colorstr.chars();
@@ -86,78 +88,27 @@ pub fn color2instr(color: u32) -> Result<(Dir, i64)> {
         '2' => Dir::W,
         '3' => Dir::N,
         _ => {
-            return Err(eyre!("could not parse dir char {}", dirchar));
+            return Err(Error::invalid_char("direction", dirchar).into());
         }
     };
     let diststr = chars.as_str();
     Ok((dir, i64::from_str_radix(diststr, 16)?))
 }
 
-pub fn instructions2points(instructions: &[(Dir, i64)]) -> Vec<(i64, i64)> {
-    let mut points = vec![];
+pub fn instructions2points(instructions: &[(Dir, i64)]) -> Vec<Point2> {
     instructions
         .iter()
-        .fold((0_i64, 0_i64), |last, &(dir, meters)| {
-            let next = (
-                last.0
-                    + match dir {
-                        Dir::W => -meters,
-                        Dir::E => meters,
-                        _ => 0,
-                    },
-                last.1
-                    + match dir {
-                        Dir::N => -meters,
-                        Dir::S => meters,
-                        _ => 0,
-                    },
-            );
-            points.push(next);
-            next
-        });
-    points
+        .scan(Point2::ORIGIN, |point, &(dir, meters)| {
+            *point = point.go(dir, meters);
+            Some(*point)
+        })
+        .collect()
 }
 
 pub fn calc_area(instructions: Vec<(Dir, i64)>) -> Result<i64> {
-    let perimeter = instructions
-        .iter()
-        .map(|(_, meters)| meters)
-        .copied()
-        .sum::<i64>();
-    let points = instructions
-        .into_iter()
-        .scan((0_i64, 0_i64), |point, (dir, meters)| {
-            let old = *point;
-            *point = (
-                point.0
-                    + match dir {
-                        Dir::E => meters,
-                        Dir::W => -meters,
-                        _ => 0,
-                    },
-                point.1
-                    + match dir {
-                        Dir::S => meters,
-                        Dir::N => -meters,
-                        _ => 0,
-                    },
-            );
-            Some(old)
-        })
-        .collect::<Vec<_>>();
-    // Shoelace formula:
-    let mut area = 0_f64;
-    for i in 0..points.len() {
-        let i0 = i;
-        let i1 = (i + 1) % (points.len());
-        let x0 = points[i0].0 as f64;
-        let x1 = points[i1].0 as f64;
-        let y0 = points[i0].1 as f64;
-        let y1 = points[i1].1 as f64;
-        area += (x0 * y1 - x1 * y0) / 2_f64;
-    }
-    // Pick's theorem:
-    Ok((area + perimeter as f64 / 2_f64 + 1_f64).round() as i64)
+    let points = instructions2points(&instructions);
+    // Pick's theorem, with the trench as the boundary:
+    Ok(geom::interior_points(&points) + geom::boundary_points(&points))
 }
 
 #[test]
@@ -235,3 +186,102 @@ fn test_botleft_missing() -> Result<()> {
     assert_eq!(calc_area(instructions)?, 48);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day18;
+
+impl Solver for Day18 {
+    const DAY: u32 = 18;
+    type Input = Vec<(Dir, i64, u32)>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(input.iter().map(|(dir, meters, color)| {
+            let d = match dir {
+                Dir::N => 'U',
+                Dir::E => 'R',
+                Dir::S => 'D',
+                Dir::W => 'L',
+                _ => unreachable!(),
+            };
+            format!("{} {} (#{:06x})", d, meters, color)
+        }))
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day18a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day18b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day18;
+    A: EXAMPLE => 62;
+    B: EXAMPLE => 952408144115_u64;
+}
+
+/// Random closed loop with the given number of columns: up the left
+/// side, right along the tops of the columns, down the right side and
+/// back left along the bottom; the bottom has up to `columns * max`
+/// meters
+fn generate_loop(g: &mut prop::Gen, columns: usize, max: i64) -> Vec<(Dir, i64)> {
+    // Consecutive heights differ, so that there are no empty moves
+    let mut heights = vec![g.range(1..=max)];
+    for i in 1..columns {
+        let height = g.range(1..=max - 1);
+        heights.push(if height >= heights[i - 1] {
+            height + 1
+        } else {
+            height
+        });
+    }
+    let widths = g.vec(columns, |g| g.range(1..=max));
+    let mut instructions = vec![(Dir::N, heights[0])];
+    for i in 0..columns {
+        if i > 0 {
+            let diff = heights[i] - heights[i - 1];
+            instructions.push((if diff > 0 { Dir::N } else { Dir::S }, diff.abs()));
+        }
+        instructions.push((Dir::E, widths[i]));
+    }
+    instructions.push((Dir::S, heights[columns - 1]));
+    instructions.push((Dir::W, widths.iter().sum()));
+    instructions
+}
+
+impl prop::Generate for Day18 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // Part b reads the instructions of another loop from the
+        // colors, with the same number of columns
+        let columns = g.len(1);
+        let mut instructions = generate_loop(g, columns, 10);
+        let mut colors = generate_loop(g, columns, 0xfffff / columns as i64);
+        let (rot1, rot2) = (
+            g.range(0..=instructions.len() - 1),
+            g.range(0..=colors.len() - 1),
+        );
+        instructions.rotate_left(rot1);
+        colors.rotate_left(rot2);
+        instructions
+            .into_iter()
+            .zip(colors)
+            .map(|((dir, meters), (cdir, cmeters))| {
+                let cdir = match cdir {
+                    Dir::E => 0,
+                    Dir::S => 1,
+                    Dir::W => 2,
+                    _ => 3,
+                };
+                (dir, meters, (cmeters as u32) << 4 | cdir)
+            })
+            .collect()
+    }
+}
+
+aoc::properties!(Day18);
@@ -0,0 +1,31 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashMap;
+
+pub fn process(input: (Vec<Workflow>, Vec<Part>)) -> Result<u64> {
+    let (workflows, parts) = input;
+    let workflows = workflows
+        .into_iter()
+        .map(|w| (w.wname, w))
+        .collect::<HashMap<_, _>>();
+    let mut totals: [u64; 4] = Default::default();
+    for part in parts {
+        let mut wname = Some(Wname::try_from("in").unwrap());
+        while let Some(current) = wname {
+            let workflow = &workflows[&current];
+            if let Some(act) = workflow.eval(&part) {
+                if act == Action::Accept {
+                    for par in Par::ALL {
+                        totals[par as usize] += part.get(par);
+                    }
+                }
+                wname = act.get_wname();
+            }
+        }
+    }
+    Ok(totals.into_iter().sum())
+}
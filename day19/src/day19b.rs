@@ -0,0 +1,56 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashMap;
+
+use aoc::ranges::{Cuboid, Interval};
+
+/// Ranges of the x, m, a and s ratings
+pub type Range = Cuboid<4>;
+
+pub const ALL: Range = Cuboid::new([Interval::new(1, 4000); 4]);
+
+/// Ratings that match the condition, and the ones that don't
+pub fn split(range: &Range, cond: &Cond) -> (Range, Range) {
+    let axis = cond.par as usize;
+    let value = cond.value as i64;
+    if cond.op == Op::Lt {
+        range.split(axis, value)
+    } else {
+        let (nonmatch, matching) = range.split(axis, value + 1);
+        (matching, nonmatch)
+    }
+}
+
+fn weval(workflows: &HashMap<Wname, Workflow>, mut range: Range, wname: Wname) -> u64 {
+    let workflow = &workflows[&wname];
+    let mut result = 0;
+    for rule in &workflow.rules {
+        if let Some(cond) = &rule.cond {
+            let (matching, nonmatch) = split(&range, cond);
+            if let Some(next_wname) = rule.act.get_wname() {
+                result += weval(workflows, matching, next_wname);
+            } else if rule.act == Action::Accept {
+                result += matching.volume();
+            }
+            range = nonmatch;
+        } else if let Some(next_wname) = rule.act.get_wname() {
+            return result + weval(workflows, range, next_wname);
+        } else if rule.act == Action::Accept {
+            return result + range.volume();
+        }
+    }
+    result
+}
+
+pub fn process(input: (Vec<Workflow>, Vec<Part>)) -> Result<u64> {
+    let (workflows, _) = input;
+    let workflows = workflows
+        .into_iter()
+        .map(|w| (w.wname, w))
+        .collect::<HashMap<_, _>>();
+    Ok(weval(&workflows, ALL, Wname::try_from("in").unwrap()))
+}
@@ -4,7 +4,11 @@
 
 pub use aoc::*;
 
+pub mod day19a;
+pub mod day19b;
+
 // use std::collections::BTreeMap;
+use std::fmt;
 
 pub const EXAMPLE: &str = "px{a<2006:qkq,m>2090:A,rfg}
 pv{a>1716:R,A}
@@ -40,14 +44,14 @@ impl Par {
 }
 
 impl TryFrom<char> for Par {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             'x' => Ok(Par::X),
             'm' => Ok(Par::M),
             'a' => Ok(Par::A),
             's' => Ok(Par::S),
-            other => Err(eyre!("invalid par {}", other)),
+            other => Err(Error::invalid_char("par", other)),
         }
     }
 }
@@ -68,6 +72,27 @@ impl Action {
     }
 }
 
+impl fmt::Display for Par {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Par::X => write!(f, "x"),
+            Par::M => write!(f, "m"),
+            Par::A => write!(f, "a"),
+            Par::S => write!(f, "s"),
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Goto(wname) => write!(f, "{}", wname),
+            Action::Accept => write!(f, "A"),
+            Action::Reject => write!(f, "R"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum Op {
     Lt,
@@ -75,17 +100,26 @@ pub enum Op {
 }
 
 impl TryFrom<char> for Op {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '<' => Ok(Op::Lt),
             '>' => Ok(Op::Gt),
-            other => Err(eyre!("invalid op {}", other)),
+            other => Err(Error::invalid_char("op", other)),
         }
     }
 }
 
-#[derive(Debug)]
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Lt => write!(f, "<"),
+            Op::Gt => write!(f, ">"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub struct Cond {
     pub par: Par,
     pub op: Op,
@@ -105,7 +139,13 @@ impl Cond {
     }
 }
 
-#[derive(Debug)]
+impl fmt::Display for Cond {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.par, self.op, self.value)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub struct Rule {
     pub cond: Option<Cond>,
     pub act: Action,
@@ -117,6 +157,16 @@ impl Rule {
     }
 }
 
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(cond) = &self.cond {
+            write!(f, "{}:", cond)?;
+        }
+        write!(f, "{}", self.act)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub struct Workflow {
     pub wname: Wname,
     pub rules: Vec<Rule>,
@@ -130,7 +180,13 @@ impl Workflow {
     }
 }
 
-#[derive(Debug)]
+impl fmt::Display for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{{{}}}", self.wname, unparse::join(&self.rules, ","))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub struct Part(pub [u64; 4]);
 
 impl Part {
@@ -139,16 +195,27 @@ impl Part {
     }
 }
 
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, m, a, s] = self.0;
+        write!(f, "{{x={},m={},a={},s={}}}", x, m, a, s)
+    }
+}
+
 pub mod parser {
     use aoc::parser::*;
 
     use super::*;
 
     fn wname(input: &str) -> IResult<&str, Wname> {
-        let (input, wname_chars) =
-            multi::many1(character::satisfy(|c| c.is_ascii_lowercase()))(input)?;
-        let wname_str: String = wname_chars.into_iter().collect();
-        Ok((input, Wname::try_from(wname_str.as_str()).unwrap()))
+        let (rest, name) = lowercase_str(input)?;
+        match Wname::try_from(name.as_str()) {
+            Ok(wname) => Ok((rest, wname)),
+            Err(_) => {
+                let e = aoc::Error::shape(format!("workflow name {} is too long", name));
+                fail(input, e.to_string())
+            }
+        }
     }
 
     fn par_name(input: &str) -> IResult<&str, Par> {
@@ -174,8 +241,8 @@ pub mod parser {
     }
 
     fn action_goto(input: &str) -> IResult<&str, Action> {
-        let (input, name) = lowercase_str(input)?;
-        Ok((input, Action::Goto(name.as_str().try_into().unwrap())))
+        let (input, name) = wname(input)?;
+        Ok((input, Action::Goto(name)))
     }
 
     fn action(input: &str) -> IResult<&str, Action> {
@@ -238,9 +305,9 @@ pub mod parser {
     }
 
     fn parse_all(input: &str) -> IResult<&str, (Vec<Workflow>, Vec<Part>)> {
-        let (input, workflows) = multi::many1(workflow)(input)?;
+        let (input, workflows) = context("workflows", multi::many1(workflow))(input)?;
         let (input, _) = character::newline(input)?;
-        let (input, parts) = multi::many1(part)(input)?;
+        let (input, parts) = context("parts", multi::many1(part))(input)?;
         Ok((input, (workflows, parts)))
     }
 
@@ -256,3 +323,88 @@ fn test() -> Result<()> {
     assert_eq!(input.1.len(), 5);
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day19;
+
+impl Solver for Day19 {
+    const DAY: u32 = 19;
+    type Input = (Vec<Workflow>, Vec<Part>);
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        let (workflows, parts) = input;
+        format!("{}\n{}", unparse::lines(workflows), unparse::lines(parts))
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day19a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day19b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day19;
+    A: EXAMPLE => 19114;
+    B: EXAMPLE => 167409079868000_u64;
+}
+
+impl prop::Generate for Day19 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // Workflows only send parts to the ones after them, so that
+        // there are no cycles; the first one is "in"
+        let len = g.len(1);
+        let mut wnames = vec!["in".to_string()];
+        while wnames.len() < len {
+            let wlen = g.range(1..=3);
+            let wname = g.string(wlen, "abcdefghijklmnopqrstuvwxyz");
+            if !wnames.contains(&wname) {
+                wnames.push(wname);
+            }
+        }
+        let wnames = wnames
+            .iter()
+            .map(|w| Wname::try_from(w.as_str()).unwrap())
+            .collect::<Vec<_>>();
+        let workflows = (0..len)
+            .map(|i| {
+                let action = |g: &mut prop::Gen| match g.range(0..=2) {
+                    0 if i + 1 < len => Action::Goto(*g.choose(&wnames[i + 1..])),
+                    1 => Action::Accept,
+                    _ => Action::Reject,
+                };
+                let conds = g.range(0..=3);
+                let mut rules = g.vec(conds, |g| {
+                    let cond = Cond {
+                        par: *g.choose(&Par::ALL),
+                        op: if g.bool() { Op::Lt } else { Op::Gt },
+                        value: g.range(1..=4000),
+                    };
+                    Rule {
+                        cond: Some(cond),
+                        act: action(g),
+                    }
+                });
+                rules.push(Rule {
+                    cond: None,
+                    act: action(g),
+                });
+                Workflow {
+                    wname: wnames[i],
+                    rules,
+                }
+            })
+            .collect::<Vec<_>>();
+        let len = g.len(1);
+        let parts = g.vec(len, |g| Part([(); 4].map(|_| g.range(1..=4000))));
+        (workflows, parts)
+    }
+}
+
+aoc::properties!(Day19);
@@ -5,8 +5,12 @@
 pub use aoc::*;
 
 use std::collections::BTreeMap;
+use std::fmt;
 use std::str::FromStr;
 
+pub mod day20a;
+pub mod day20b;
+
 pub const EXAMPLE1: &str = "broadcaster -> a, b, c
 %a -> b
 %b -> c
@@ -31,9 +35,17 @@ impl From<&str> for Mname {
 }
 
 impl FromStr for Mname {
-    type Err = Report;
+    type Err = aoc::Error;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Mname(copstr::Str::<6>::try_from(s)?))
+        copstr::Str::<6>::try_from(s)
+            .map(Mname)
+            .map_err(|_| aoc::Error::shape(format!("module name {} is too long", s)))
+    }
+}
+
+impl fmt::Display for Mname {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
 
@@ -47,12 +59,12 @@ pub enum Mtype {
 }
 
 impl TryFrom<char> for Mtype {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '%' => Ok(Mtype::FlipFlop),
             '&' => Ok(Mtype::Conjunct),
-            other => Err(eyre!("invalid module type {}", other)),
+            other => Err(Error::invalid_char("module type", other)),
         }
     }
 }
@@ -62,6 +74,21 @@ pub struct Module {
     pub mname: Mname,
     pub mtype: Mtype,
     pub dsts: Vec<Mname>,
+    /// Line of the module in the input; modules that only appear as
+    /// destinations have type `None` and are not written back
+    pub line: usize,
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mtype {
+            Mtype::Broadcast => write!(f, "broadcaster")?,
+            Mtype::FlipFlop => write!(f, "%{}", self.mname)?,
+            Mtype::Conjunct => write!(f, "&{}", self.mname)?,
+            Mtype::None => return Ok(()),
+        }
+        write!(f, " -> {}", unparse::join(&self.dsts, ", "))
+    }
 }
 
 pub mod parser {
@@ -75,8 +102,11 @@ pub mod parser {
     }
 
     fn mname(input: &str) -> IResult<&str, Mname> {
-        let (input, mname) = character::alpha1(input)?;
-        Ok((input, mname.into()))
+        let (rest, mname) = character::alpha1(input)?;
+        match mname.parse() {
+            Ok(mname) => Ok((rest, mname)),
+            Err(e) => fail(input, e.to_string()),
+        }
     }
 
     fn module_line(input: &str) -> IResult<&str, Module> {
@@ -91,6 +121,7 @@ pub mod parser {
                 mname: name,
                 mtype,
                 dsts,
+                line: 0,
             },
         ))
     }
@@ -105,6 +136,7 @@ pub mod parser {
                 mname: Mname("0".try_into().unwrap()),
                 mtype: Mtype::Broadcast,
                 dsts,
+                line: 0,
             },
         ))
     }
@@ -126,7 +158,8 @@ pub mod parser {
             .collect::<Vec<Mname>>();
         let mut modules = modules
             .into_iter()
-            .map(|m| (m.mname, m))
+            .enumerate()
+            .map(|(line, m)| (m.mname, Module { line, ..m }))
             .collect::<BTreeMap<_, _>>();
         for dst_mname in dsts {
             modules.entry(dst_mname).or_default();
@@ -174,6 +207,33 @@ pub fn sts_init(modules: &BTreeMap<Mname, Module>) -> BTreeMap<Mname, ModState>
     sts
 }
 
+/// Image of the states of the modules, in a square: flip-flops that are
+/// on are yellow, and conjunctions get redder as their inputs get high
+pub fn modules_image(
+    modules: &BTreeMap<Mname, Module>,
+    sts: &BTreeMap<Mname, ModState>,
+) -> render::Image {
+    let side = (1..).find(|s| s * s >= modules.len()).unwrap_or(1);
+    let mut image = render::Image::new(side, side, render::Rgb::BLACK);
+    for (i, (mname, module)) in modules.iter().enumerate() {
+        let Some(msts) = sts.get(mname) else {
+            continue;
+        };
+        let colour = match module.mtype {
+            Mtype::FlipFlop if msts.state => render::Rgb::YELLOW,
+            Mtype::FlipFlop => render::Rgb::DARK,
+            Mtype::Conjunct => {
+                let high = msts.memory.values().filter(|v| **v).count();
+                let inputs = msts.memory.len().max(1);
+                render::Rgb::DARK.blend(render::Rgb::RED, high as f64 / inputs as f64)
+            }
+            _ => render::Rgb::GRAY,
+        };
+        image.set(aoc::grid::Pos::new(i % side, i / side), colour);
+    }
+    image
+}
+
 pub fn eval<'a>(
     module: &'a Module,
     msts: &'a mut ModState,
@@ -204,7 +264,7 @@ pub fn eval<'a>(
         }
         Mtype::Conjunct => {
             msts.memory.insert(src_mname, pulse);
-            // eprintln!("{} memory {:?}", module.mname.0, msts.memory);
+            log::trace!("{} memory {:?}", module.mname.0, msts.memory);
             let pulse = !msts.memory.values().all(|v| *v);
             Box::new(
                 module
@@ -217,3 +277,89 @@ pub fn eval<'a>(
         Mtype::None => Box::new(std::iter::empty()),
     }
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day20;
+
+impl Solver for Day20 {
+    const DAY: u32 = 20;
+    type Input = BTreeMap<Mname, Module>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        let mut modules = input
+            .values()
+            .filter(|m| m.mtype != Mtype::None)
+            .collect::<Vec<_>>();
+        modules.sort_by_key(|m| m.line);
+        unparse::lines(modules)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day20a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day20b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day20, untested: [B];
+    A: EXAMPLE1 => 32000000;
+    A: EXAMPLE2 => 11687500;
+}
+
+impl prop::Generate for Day20 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // Pulses only go forward, so that every button press ends
+        let len = g.len(1).min(8);
+        let mut names = vec![];
+        while names.len() < len + 2 {
+            let name_len = g.range(1..=5);
+            let name = Mname::from(g.string(name_len, "abcdefghijklmnopqrstuvwxyz").as_str());
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        // The last two names are only destinations
+        let mut modules = (0..len)
+            .map(|i| {
+                let fanout = g.range(1..=2);
+                let dsts = g.vec(fanout, |g| names[g.range(i + 1..=len + 1)]);
+                let mtype = match (i, g.bool()) {
+                    (0, _) => Mtype::Broadcast,
+                    (_, true) => Mtype::FlipFlop,
+                    (_, false) => Mtype::Conjunct,
+                };
+                let mname = if i == 0 { "0".into() } else { names[i] };
+                Module {
+                    mname,
+                    mtype,
+                    dsts,
+                    line: 0,
+                }
+            })
+            .collect::<Vec<_>>();
+        g.shuffle(&mut modules);
+        let dsts = modules
+            .iter()
+            .flat_map(|m| m.dsts.iter())
+            .copied()
+            .collect::<Vec<Mname>>();
+        let mut input = modules
+            .into_iter()
+            .enumerate()
+            .map(|(line, m)| (m.mname, Module { line, ..m }))
+            .collect::<BTreeMap<_, _>>();
+        for dst_mname in dsts {
+            input.entry(dst_mname).or_default();
+        }
+        input
+    }
+}
+
+aoc::properties!(Day20, untested: [B]);
@@ -0,0 +1,59 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashSet;
+
+use sqrid::postrait::PosT;
+
+pub use sqrid::Dir;
+pub type Sqrid = sqrid::sqrid_create!(131, 131, false);
+// pub type Sqrid = sqrid::sqrid_create!(11, 11, false);
+pub type Pos = sqrid::pos_create!(Sqrid);
+pub type Grid = sqrid::grid_create!(Sqrid, Cell);
+
+pub type GridDebug = sqrid::grid_create!(Sqrid, char);
+
+pub type Steps = i32;
+
+fn dfs(
+    size: u16,
+    grid: &Grid,
+    pos: Pos,
+    visited: &mut HashSet<(Pos, Steps)>,
+    end: &mut HashSet<Pos>,
+    stepsleft: i32,
+) {
+    if visited.contains(&(pos, stepsleft)) {
+        return;
+    }
+    if stepsleft == 0 {
+        end.insert(pos);
+        return;
+    }
+    for dir in Dir::iter::<false>() {
+        let Ok(newpos) = pos + dir else { continue };
+        let newpos_t = newpos.tuple();
+        if newpos_t.0 >= size || newpos_t.1 >= size {
+            continue;
+        }
+        if grid[newpos] == Cell::Rock {
+            continue;
+        }
+        dfs(size, grid, newpos, visited, end, stepsleft - 1);
+    }
+    visited.insert((pos, stepsleft));
+}
+
+pub fn process(size: u16, steps: Steps, input: Vec<Vec<Cell>>) -> Result<usize> {
+    let grid = Grid::try_from(input)?;
+    let start = Pos::iter()
+        .find(|p| grid[p] == Cell::Start)
+        .ok_or_else(|| Error::shape("no start cell"))?;
+    let mut visited = HashSet::<(Pos, Steps)>::new();
+    let mut end = HashSet::<Pos>::new();
+    dfs(size, &grid, start, &mut visited, &mut end, steps);
+    Ok(end.len())
+}
@@ -0,0 +1,90 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashSet;
+
+use aoc::geom::Point2;
+pub use sqrid::Dir;
+pub type Steps = i64;
+
+fn into_map(size: i64, input: Vec<Vec<Cell>>) -> (Point2, HashSet<Point2>) {
+    let mut rocks = <HashSet<_>>::new();
+    let mut start = Point2::ORIGIN;
+    for (y, line) in input.into_iter().enumerate() {
+        for (x, cell) in line.into_iter().enumerate() {
+            let t = Point2::new(x as i64, y as i64);
+            if cell == Cell::Start {
+                start = t;
+            } else if cell == Cell::Rock {
+                rocks.insert(t);
+                rocks.insert(t - Point2::new(size, 0));
+                rocks.insert(t - Point2::new(0, size));
+                rocks.insert(t - Point2::new(size, size));
+            }
+        }
+    }
+    (start, rocks)
+}
+
+/// Image of the plots in `frontier`, over the tiled map, up to `radius`
+/// steps away from `start`
+fn plots_image(
+    size: i64,
+    rocks: &HashSet<Point2>,
+    frontier: &HashSet<Point2>,
+    start: Point2,
+    radius: i64,
+) -> render::Image {
+    let min = start - Point2::new(radius, radius);
+    let max = start + Point2::new(radius, radius);
+    let pos = |p: Point2| aoc::grid::Pos::new((p.x - min.x) as usize, (p.y - min.y) as usize);
+    let (width, height) = ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+    let mut image = render::Image::new(width, height, render::Rgb::WHITE);
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            if rocks.contains(&Point2::new(x % size, y % size)) {
+                image.set(pos(Point2::new(x, y)), render::Rgb::DARK);
+            }
+        }
+    }
+    image.overlay(frontier.iter().map(|&p| pos(p)), render::Rgb::GREEN);
+    image
+}
+
+pub fn process(size: i64, steps: Steps, input: Vec<Vec<Cell>>) -> Result<usize> {
+    let (start, rocks) = into_map(size, input);
+    let mut frontier = [start].into_iter().collect::<HashSet<Point2>>();
+    let wanted = [65, 196, 327];
+    let mut coefs = vec![];
+    let radius = steps.min(wanted[wanted.len() - 1]);
+    let mut animation = anim::Animation::from_env("day21b")?;
+    for i in 0..steps {
+        if wanted.contains(&i) {
+            log::debug!("step {}: {} plots", i, frontier.len());
+            render::dump(&format!("day21b-{}", i), || {
+                plots_image(size, &rocks, &frontier, start, i)
+            })?;
+            coefs.push(frontier.len())
+        }
+        if i == wanted[wanted.len() - 1] {
+            break;
+        }
+        frontier = frontier
+            .into_iter()
+            .flat_map(|p| {
+                let rocks = &rocks;
+                Dir::ALL4.into_iter().filter_map(move |d| {
+                    let newp = p.step(d);
+                    let basep = Point2::new(newp.x % size, newp.y % size);
+                    (!rocks.contains(&basep)).then_some(newp)
+                })
+            })
+            .collect();
+        animation.frame(|| plots_image(size, &rocks, &frontier, start, radius));
+    }
+    animation.finish()?;
+    Ok(frontier.len())
+}
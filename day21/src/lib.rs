@@ -4,6 +4,11 @@
 
 pub use aoc::*;
 
+use std::fmt;
+
+pub mod day21a;
+pub mod day21b;
+
 pub const EXAMPLE: &str = "...........
 .....###.#.
 .###.##..#.
@@ -26,13 +31,13 @@ pub enum Cell {
 }
 
 impl TryFrom<char> for Cell {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '.' => Ok(Cell::Empty),
             '#' => Ok(Cell::Rock),
             'S' => Ok(Cell::Start),
-            other => Err(eyre!("invalid cell {}", other)),
+            other => Err(Error::invalid_char("cell", other)),
         }
     }
 }
@@ -47,24 +52,19 @@ impl From<Cell> for char {
     }
 }
 
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
+
 pub mod parser {
     use aoc::parser::*;
 
     use super::*;
 
-    fn cell(input: &str) -> IResult<&str, Cell> {
-        let (input, c) = character::one_of(r".#S")(input)?;
-        Ok((input, Cell::try_from(c).unwrap()))
-    }
-
-    fn line(input: &str) -> IResult<&str, Vec<Cell>> {
-        let (input, cells) = multi::many1(cell)(input)?;
-        let (input, _) = character::newline(input)?;
-        Ok((input, cells))
-    }
-
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
-        aoc::parse_with!(multi::many1(line), bufin)
+        aoc::parse_with!(char_grid, bufin)
     }
 }
 
@@ -74,3 +74,73 @@ fn test() -> Result<()> {
     assert_eq!(input.len(), 11);
     Ok(())
 }
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day21 {
+    pub size: u16,
+    pub steps1: i32,
+    pub steps2: i64,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Day21 {
+            size: 131,
+            steps1: 64,
+            steps2: 26501365,
+        }
+    }
+}
+
+impl Solver for Day21 {
+    const DAY: u32 = 21;
+    type Input = Vec<Vec<Cell>>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::char_grid(input)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day21a::process(self.size, self.steps1, input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day21b::process(self.size.into(), self.steps2, input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day21;
+    A(Day21 { size: 11, steps1: 6, ..Day21::default() }): EXAMPLE => 16;
+    B(Day21 { size: 11, steps2: 10, ..Day21::default() }): EXAMPLE => 50;
+    B(Day21 { size: 11, steps2: 50, ..Day21::default() }): EXAMPLE => 1594;
+    B(Day21 { size: 11, steps2: 100, ..Day21::default() }): EXAMPLE => 6536;
+}
+
+impl prop::Generate for Day21 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        let size = usize::from(self.size);
+        let mut grid = g.grid(size, size, |g| {
+            if g.one_in(6) {
+                Cell::Rock
+            } else {
+                Cell::Empty
+            }
+        });
+        grid[size / 2][size / 2] = Cell::Start;
+        grid
+    }
+}
+
+aoc::properties!(
+    Day21,
+    Day21 {
+        size: 11,
+        steps1: 6,
+        steps2: 20
+    }
+);
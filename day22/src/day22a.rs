@@ -0,0 +1,26 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use rayon::prelude::*;
+
+fn can_disintegrate(bricks: &[Brick], b: &Brick) -> bool {
+    let bricks = bricks
+        .iter()
+        .filter(|o| o != &b)
+        .copied()
+        .collect::<Vec<_>>();
+    bricks.par_iter().all(|o| falls_to(&bricks, o).is_none())
+}
+
+pub fn process(mut bricks: Vec<Brick>) -> Result<usize> {
+    let mut animation = anim::Animation::from_env("day22a")?;
+    settle_bricks(&mut bricks, &mut animation);
+    animation.finish()?;
+    Ok(bricks
+        .par_iter()
+        .filter(|b| can_disintegrate(&bricks, b))
+        .count())
+}
@@ -0,0 +1,23 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use rayon::prelude::*;
+
+fn would_fall(bricks: &[Brick], b: &Brick) -> usize {
+    let mut bricks = bricks
+        .iter()
+        .filter(|o| o != &b)
+        .copied()
+        .collect::<Vec<_>>();
+    settle_bricks(&mut bricks, &mut anim::Animation::none())
+}
+
+pub fn process(mut bricks: Vec<Brick>) -> Result<usize> {
+    let mut animation = anim::Animation::from_env("day22b")?;
+    settle_bricks(&mut bricks, &mut animation);
+    animation.finish()?;
+    Ok(bricks.par_iter().map(|b| would_fall(&bricks, b)).sum())
+}
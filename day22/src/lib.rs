@@ -4,9 +4,12 @@
 
 pub use aoc::*;
 
-use std::cmp::min;
+use std::cmp::Reverse;
 use std::collections::HashSet;
 
+pub mod day22a;
+pub mod day22b;
+
 pub const EXAMPLE: &str = "1,0,1~1,2,1
 0,0,2~2,0,2
 0,2,3~2,2,3
@@ -16,37 +19,9 @@ pub const EXAMPLE: &str = "1,0,1~1,2,1
 1,1,8~1,1,9
 ";
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Range(i64, i64);
-
-impl Range {
-    pub fn new(a: i64, b: i64) -> Self {
-        Range(min(a, b), std::cmp::max(a, b))
-    }
-    pub fn size(&self) -> i64 {
-        (self.1 - self.0) + 1
-    }
-    pub fn min(&self) -> i64 {
-        self.0
-    }
-    pub fn max(&self) -> i64 {
-        self.1
-    }
-    pub fn contains(&self, value: i64) -> bool {
-        self.0 <= value && value <= self.1
-    }
-    pub fn intersection(&self, other: &Range) -> Option<Range> {
-        if self.0 <= other.0 && other.0 <= self.1 {
-            Some(Range::new(other.0, min(other.1, self.1)))
-        } else if other.0 <= self.0 && self.0 <= other.1 {
-            Some(Range::new(self.0, min(other.1, self.1)))
-        } else {
-            None
-        }
-    }
-}
+pub use aoc::ranges::{Cuboid, Interval};
 
-pub type Brick = (Range, Range, Range);
+pub type Brick = Cuboid<3>;
 
 pub mod parser {
     use aoc::parser::*;
@@ -69,11 +44,11 @@ pub mod parser {
         let (input, _) = character::newline(input)?;
         Ok((
             input,
-            (
-                Range::new(p0.0, p1.0),
-                Range::new(p0.1, p1.1),
-                Range::new(p0.2, p1.2),
-            ),
+            Cuboid::new([
+                Interval::spanning(p0.0, p1.0),
+                Interval::spanning(p0.1, p1.1),
+                Interval::spanning(p0.2, p1.2),
+            ]),
         ))
     }
 
@@ -90,33 +65,64 @@ fn test() -> Result<()> {
 }
 
 pub fn brick_on(bricks: &[Brick], b: &Brick) -> Option<Brick> {
-    if b.2.min() == 1 {
+    if b[2].start == 1 {
         return None;
     }
     bricks
         .iter()
         .filter(|other| {
-            b.0.intersection(&other.0).is_some()
-                && b.1.intersection(&other.1).is_some()
-                && other.2.max() < b.2.min()
+            b[0].overlaps(&other[0]) && b[1].overlaps(&other[1]) && other[2].end < b[2].start
         })
-        .max_by_key(|o| o.2.max())
+        .max_by_key(|o| o[2].end)
         .copied()
 }
 
 pub fn falls_to(bricks: &[Brick], b: &Brick) -> Option<i64> {
     if let Some(other) = brick_on(bricks, b) {
-        if b.2.min() != other.2.max() + 1 {
-            Some(other.2.max() + 1)
+        if b[2].start != other[2].end + 1 {
+            Some(other[2].end + 1)
         } else {
             None
         }
     } else {
-        (b.2.min() != 1).then_some(1)
+        (b[2].start != 1).then_some(1)
     }
 }
 
-pub fn settle_bricks(bricks: &mut [Brick]) -> usize {
+/// Side view of the bricks, looking along the y axis, with the ground
+/// at the bottom; the image has the values of `xs` and the levels up
+/// to `top`
+pub fn bricks_image(bricks: &[Brick], xs: Interval, top: i64) -> render::Image {
+    let (width, height) = (xs.len() as usize, top as usize + 1);
+    let mut image = render::Image::new(width, height, render::Rgb::DARK);
+    let ground = (0..width).map(|x| aoc::grid::Pos::new(x, height - 1));
+    image.overlay(ground, render::Rgb::GRAY);
+    // The bricks in front are drawn last
+    let mut order = (0..bricks.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| Reverse(bricks[i][1].start));
+    for i in order {
+        let b = &bricks[i];
+        let shade = |k: usize| (55 + i * k % 200) as u8;
+        let colour = render::Rgb(shade(97), shade(57), shade(31));
+        for x in b[0].start..=b[0].end {
+            for z in b[2].start..=b[2].end {
+                let pos = aoc::grid::Pos::new((x - xs.start) as usize, (top - z) as usize);
+                image.set(pos, colour);
+            }
+        }
+    }
+    image
+}
+
+/// Lets the bricks fall, animating each round; returns the number of
+/// bricks that fell
+pub fn settle_bricks(bricks: &mut [Brick], animation: &mut anim::Animation) -> usize {
+    let xs = Interval::new(
+        bricks.iter().map(|b| b[0].start).min().unwrap_or(0),
+        bricks.iter().map(|b| b[0].end).max().unwrap_or(0),
+    );
+    let top = bricks.iter().map(|b| b[2].end).max().unwrap_or(0);
+    animation.frame(|| bricks_image(bricks, xs, top));
     let mut changed = true;
     let mut fell = HashSet::<usize>::new();
     while changed {
@@ -124,12 +130,72 @@ pub fn settle_bricks(bricks: &mut [Brick]) -> usize {
         changed = false;
         for (i, b) in bricks.iter_mut().enumerate() {
             if let Some(z) = falls_to(&old, b) {
-                let height = b.2.max() - b.2.min();
-                b.2 = Range::new(z, z + height);
+                let height = b[2].end - b[2].start;
+                b[2] = Interval::new(z, z + height);
                 fell.insert(i);
                 changed = true;
             }
         }
+        animation.frame(|| bricks_image(bricks, xs, top));
     }
     fell.len()
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day22;
+
+impl Solver for Day22 {
+    const DAY: u32 = 22;
+    type Input = Vec<Brick>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(input.iter().map(|b| {
+            format!(
+                "{},{},{}~{},{},{}",
+                b[0].start, b[1].start, b[2].start, b[0].end, b[1].end, b[2].end
+            )
+        }))
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day22a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day22b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day22;
+    A: EXAMPLE => 5;
+    B: EXAMPLE => 7;
+}
+
+impl prop::Generate for Day22 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // Each brick starts above all the previous ones, so that they
+        // don't overlap
+        let mut z = 0;
+        let len = g.len(1);
+        g.vec(len, |g| {
+            let (x, y) = (g.range(0..=4), g.range(0..=4));
+            z += g.range(1..=3);
+            let mut size = [0, 0, 0];
+            size[g.range(0..=2)] = g.range(0..=2);
+            let brick = Cuboid::new([
+                Interval::new(x, (x + size[0]).min(4)),
+                Interval::new(y, (y + size[1]).min(4)),
+                Interval::new(z, z + size[2]),
+            ]);
+            z += size[2];
+            brick
+        })
+    }
+}
+
+aoc::properties!(Day22);
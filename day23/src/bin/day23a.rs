@@ -4,16 +4,6 @@
 
 use day23::*;
 
-fn process(size: u16, bufin: impl BufRead) -> Result<usize> {
-    solve::<true>(size, bufin)
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(23, EXAMPLE.as_bytes())?, 94);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(141, stdin().lock()))
+    do_main(&Day23, Part::A)
 }
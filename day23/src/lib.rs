@@ -4,11 +4,13 @@
 
 pub use aoc::*;
 
-use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt;
 
-use sqrid::postrait::PosT;
+use aoc::graph::{Graph, NodeId};
+
+pub mod day23a;
+pub mod day23b;
 
 pub const EXAMPLE: &str = "#.#####################
 #.......#########...###
@@ -46,7 +48,7 @@ pub enum Cell {
 }
 
 impl TryFrom<char> for Cell {
-    type Error = Report;
+    type Error = aoc::Error;
     fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
             '.' => Ok(Cell::Empty),
@@ -55,7 +57,7 @@ impl TryFrom<char> for Cell {
             '>' => Ok(Cell::Slope(Dir::E)),
             'v' => Ok(Cell::Slope(Dir::S)),
             '<' => Ok(Cell::Slope(Dir::W)),
-            other => Err(eyre!("invalid cell {}", other)),
+            other => Err(Error::invalid_char("cell", other)),
         }
     }
 }
@@ -85,19 +87,8 @@ pub mod parser {
 
     use super::*;
 
-    fn cell(input: &str) -> IResult<&str, Cell> {
-        let (input, c) = character::one_of(".#^>v<")(input)?;
-        Ok((input, Cell::try_from(c).unwrap()))
-    }
-
-    fn line(input: &str) -> IResult<&str, Vec<Cell>> {
-        let (input, cells) = multi::many1(cell)(input)?;
-        let (input, _) = character::newline(input)?;
-        Ok((input, cells))
-    }
-
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
-        aoc::parse_with!(multi::many1(line), bufin)
+        aoc::parse_with!(char_grid, bufin)
     }
 }
 
@@ -109,31 +100,26 @@ fn test() -> Result<()> {
     Ok(())
 }
 
-pub type Sqrid = sqrid::sqrid_create!(141, 141, false);
-pub type Pos = sqrid::pos_create!(Sqrid);
-pub type Grid = sqrid::grid_create!(Sqrid, Cell);
-
-#[derive(Debug, Hash, PartialEq, Eq)]
-pub struct Node {
-    pub pos: Pos,
-    pub children: Vec<(Pos, usize)>,
-}
-
-impl Node {
-    pub fn new(pos: Pos) -> Self {
-        Node {
-            pos,
-            children: vec![],
-        }
+#[test]
+fn test_unreachable() -> Result<()> {
+    let input = parser::parse("#.###\n#.###\n#####\n###.#\n".as_bytes())?;
+    for part in Part::ALL {
+        let e = Day23.solve(part, input.clone()).unwrap_err();
+        let expected = Error::no_solution("end not reachable");
+        assert_eq!(e.downcast_ref(), Some(&expected));
     }
+    Ok(())
 }
 
-fn go<const SLOPES: bool>(size: u16, grid: &Grid, pos: Pos, dir: Dir) -> Option<Pos> {
-    (pos + dir).ok().filter(|newpos| {
-        let newpos_t = newpos.tuple();
-        newpos_t.0 < size
-            && newpos_t.1 < size
-            && newpos != &pos
+pub use aoc::grid::Pos;
+pub type Grid = aoc::grid::Grid<Cell>;
+
+/// Junctions, with the cells of the corridors between them
+pub type Maze = Graph<Pos, Vec<Pos>>;
+
+fn go<const SLOPES: bool>(grid: &Grid, pos: Pos, dir: Dir) -> Option<Pos> {
+    grid.go(pos, dir).filter(|newpos| {
+        newpos != &pos
             && match grid[newpos] {
                 Cell::Empty => true,
                 Cell::Slope(d) => !SLOPES || d == dir,
@@ -142,87 +128,226 @@ fn go<const SLOPES: bool>(size: u16, grid: &Grid, pos: Pos, dir: Dir) -> Option<
     })
 }
 
-pub fn is_node<const SLOPES: bool>(size: u16, grid: &Grid, pos: Pos) -> bool {
-    let pos_t = pos.tuple();
-    if grid[pos] == Cell::Wall || pos_t.0 >= size || pos_t.1 >= size {
+pub fn is_node<const SLOPES: bool>(grid: &Grid, pos: Pos) -> bool {
+    if grid[pos] == Cell::Wall {
         false
     } else {
         let num_children_noslopes = Dir::iter::<false>()
-            .filter(|dir| go::<false>(size, grid, pos, *dir).is_some())
+            .filter(|dir| go::<false>(grid, pos, *dir).is_some())
             .count();
         if !SLOPES {
             num_children_noslopes != 2
         } else {
             let num_children_slopes = Dir::iter::<false>()
-                .filter(|dir| go::<true>(size, grid, pos, *dir).is_some())
+                .filter(|dir| go::<true>(grid, pos, *dir).is_some())
                 .count();
             num_children_slopes != 2 || num_children_noslopes != 2
         }
     }
 }
 
-pub fn node_craft<const SLOPES: bool>(size: u16, grid: &Grid, pos: Pos) -> Node {
+/// Cells of the corridors that leave `pos`, up to the junction or dead
+/// end where they finish
+pub fn corridors<const SLOPES: bool>(grid: &Grid, pos: Pos) -> Vec<Vec<Pos>> {
     let visited0 = [pos].into_iter().collect::<HashSet<Pos>>();
-    Node {
-        pos,
-        children: Dir::iter::<false>()
-            .filter(|dir| go::<SLOPES>(size, grid, pos, *dir).is_some())
-            .map(|child_dir| {
-                let mut newpos = pos;
-                let mut visited = visited0.clone();
-                let mut next = vec![(newpos + child_dir).unwrap()];
-                let mut steps = 0;
-                while next.len() == 1 {
-                    steps += 1;
-                    newpos = next[0];
-                    if is_node::<SLOPES>(size, grid, newpos) {
-                        break;
-                    }
-                    visited.insert(newpos);
-                    next = Dir::iter::<false>()
-                        .filter_map(|dir| {
-                            go::<SLOPES>(size, grid, newpos, dir).filter(|p| !visited.contains(p))
-                        })
-                        .collect::<Vec<_>>();
+    Dir::iter::<false>()
+        .filter(|dir| go::<SLOPES>(grid, pos, *dir).is_some())
+        .map(|child_dir| {
+            let mut visited = visited0.clone();
+            let mut next = vec![grid.go(pos, child_dir).unwrap()];
+            let mut cells = vec![];
+            while next.len() == 1 {
+                let newpos = next[0];
+                cells.push(newpos);
+                if is_node::<SLOPES>(grid, newpos) {
+                    break;
                 }
-                (newpos, steps)
-            })
-            .collect::<Vec<(Pos, usize)>>(),
-    }
+                visited.insert(newpos);
+                next = Dir::iter::<false>()
+                    .filter_map(|dir| {
+                        go::<SLOPES>(grid, newpos, dir).filter(|p| !visited.contains(p))
+                    })
+                    .collect::<Vec<_>>();
+            }
+            cells
+        })
+        .collect::<Vec<_>>()
 }
 
+/// Length of the longest path to `end`, if any reaches it
 pub fn dfs(
-    nodes: &HashMap<Pos, Node>,
-    pos: Pos,
-    visited: &HashSet<Pos>,
-    end: Pos,
+    maze: &Maze,
+    id: NodeId,
+    visited: &mut [bool],
+    end: NodeId,
     steps: usize,
-) -> usize {
-    if pos == end {
-        return steps;
+) -> Option<usize> {
+    if id == end {
+        return Some(steps);
     }
-    if visited.contains(&pos) {
-        return 0;
+    if visited[id] {
+        return None;
     }
-    let mut newvisited = visited.clone();
-    newvisited.insert(pos);
-    let node = nodes.get(&pos).unwrap();
-    node.children
+    visited[id] = true;
+    let longest = maze
+        .edges(id)
         .iter()
-        .map(|(newpos, newsteps)| dfs(nodes, *newpos, &newvisited, end, steps + *newsteps))
-        .max()
-        .unwrap_or(0)
+        .filter_map(|(next, cells)| dfs(maze, *next, visited, end, steps + cells.len()))
+        .max();
+    visited[id] = false;
+    longest
+}
+
+/// Corridors of a hike from `id` to `end` with `longest` steps, last
+/// to first
+fn hike<'a>(
+    maze: &'a Maze,
+    id: NodeId,
+    visited: &mut [bool],
+    end: NodeId,
+    steps: usize,
+    longest: usize,
+) -> Option<Vec<&'a [Pos]>> {
+    if id == end {
+        return (steps == longest).then(Vec::new);
+    }
+    if visited[id] {
+        return None;
+    }
+    visited[id] = true;
+    let found = maze.edges(id).iter().find_map(|(next, cells)| {
+        let mut corridors = hike(maze, *next, visited, end, steps + cells.len(), longest)?;
+        corridors.push(cells);
+        Some(corridors)
+    });
+    visited[id] = false;
+    found
 }
 
-pub fn solve<const SLOPES: bool>(size: u16, bufin: impl BufRead) -> Result<usize> {
-    let input = parser::parse(bufin)?;
+pub fn solve<const SLOPES: bool>(input: Vec<Vec<Cell>>) -> Result<usize> {
     let grid = Grid::try_from(input)?;
-    let nodes = Pos::iter()
-        .filter(|p| is_node::<SLOPES>(size, &grid, *p))
-        .map(|p| (p, node_craft::<SLOPES>(size, &grid, p)))
-        .collect::<HashMap<_, _>>();
-    let start = Pos::new(1, 0)?;
-    let end = Pos::new(size - 2, size - 1)?;
-    let visited = Default::default();
-    Ok(dfs(&nodes, start, &visited, end, 0))
+    let mut maze = Maze::new();
+    for pos in grid.iter_pos().filter(|p| is_node::<SLOPES>(&grid, *p)) {
+        maze.add_node(pos);
+        for cells in corridors::<SLOPES>(&grid, pos) {
+            if let Some(&end) = cells.last() {
+                maze.add_edge(pos, end, cells);
+            }
+        }
+    }
+    let start = maze.id(&Pos::new(1, 0));
+    let end = maze.id(&Pos::new(grid.width() - 2, grid.height() - 1));
+    let (Some(start), Some(end)) = (start, end) else {
+        return Err(Error::shape("start/end not on a path").into());
+    };
+    let longest = dfs(&maze, start, &mut vec![false; maze.len()], end, 0)
+        .ok_or_else(|| Error::no_solution("end not reachable"))?;
+    let name = if SLOPES { "day23a" } else { "day23b" };
+    render::dump(name, || {
+        let mut image = render::Image::from_grid(&grid, |cell| match cell {
+            Cell::Empty => render::Rgb::WHITE,
+            Cell::Wall => render::Rgb::DARK,
+            Cell::Slope(_) => render::Rgb::GRAY,
+        });
+        let corridors = hike(&maze, start, &mut vec![false; maze.len()], end, 0, longest);
+        let cells = corridors.into_iter().flatten().rev().flatten().copied();
+        image.path(
+            [*maze.node(start)].into_iter().chain(cells),
+            render::Rgb::RED,
+        );
+        image
+    })?;
+    Ok(longest)
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day23;
+
+impl Solver for Day23 {
+    const DAY: u32 = 23;
+    type Input = Vec<Vec<Cell>>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::char_grid(input)
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day23a::process(input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day23b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day23;
+    A: EXAMPLE => 94;
+    B: EXAMPLE => 154;
+}
+
+impl prop::Generate for Day23 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // Maze of rooms at odd coordinates, carved with a random
+        // depth-first search, with some extra passages and slopes
+        let (rooms_x, rooms_y) = (g.range(1..=1 + g.size() / 4), g.range(1..=1 + g.size() / 4));
+        let (width, height) = (2 * rooms_x + 1, 2 * rooms_y + 1);
+        let mut rows = vec![vec![Cell::Wall; width]; height];
+        let mut visited = vec![vec![false; rooms_x]; rooms_y];
+        let mut stack = vec![(0, 0)];
+        visited[0][0] = true;
+        rows[1][1] = Cell::Empty;
+        while let Some(&(x, y)) = stack.last() {
+            let mut dirs = vec![];
+            if x > 0 && !visited[y][x - 1] {
+                dirs.push((x - 1, y));
+            }
+            if x + 1 < rooms_x && !visited[y][x + 1] {
+                dirs.push((x + 1, y));
+            }
+            if y > 0 && !visited[y - 1][x] {
+                dirs.push((x, y - 1));
+            }
+            if y + 1 < rooms_y && !visited[y + 1][x] {
+                dirs.push((x, y + 1));
+            }
+            if dirs.is_empty() {
+                stack.pop();
+                continue;
+            }
+            let (nx, ny) = *g.choose(&dirs);
+            visited[ny][nx] = true;
+            rows[2 * ny + 1][2 * nx + 1] = Cell::Empty;
+            rows[y + ny + 1][x + nx + 1] = Cell::Empty;
+            stack.push((nx, ny));
+        }
+        for (y, row) in rows.iter_mut().enumerate().take(height - 1).skip(1) {
+            for (x, cell) in row.iter_mut().enumerate().take(width - 1).skip(1) {
+                if (x + y) % 2 == 1 && *cell == Cell::Wall && g.one_in(6) {
+                    *cell = Cell::Empty;
+                }
+            }
+        }
+        for (y, row) in rows.iter_mut().enumerate().take(height - 1).skip(1) {
+            for (x, cell) in row.iter_mut().enumerate().take(width - 1).skip(1) {
+                if (x + y) % 2 == 1 && *cell == Cell::Empty && g.one_in(3) {
+                    let dirs = if x % 2 == 0 {
+                        [Dir::E, Dir::W]
+                    } else {
+                        [Dir::S, Dir::N]
+                    };
+                    *cell = Cell::Slope(*g.choose(&dirs));
+                }
+            }
+        }
+        rows[0][1] = Cell::Empty;
+        rows[height - 1][width - 2] = Cell::Empty;
+        rows
+    }
 }
+
+aoc::properties!(Day23);
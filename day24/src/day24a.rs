@@ -0,0 +1,31 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::geom::{Frac, Line};
+
+/// Where the paths of the stones cross in the future, ignoring `z`
+fn intercept(v: &Stone, u: &Stone) -> Option<(Frac, Frac)> {
+    let line_v = Line::new(v.0.xy(), v.1.xy());
+    let line_u = Line::new(u.0.xy(), u.1.xy());
+    let (t_v, t_u) = line_v.intersection(&line_u)?;
+    if t_v.is_negative() || t_u.is_negative() {
+        return None;
+    }
+    Some(line_v.at(t_v))
+}
+
+pub fn process(tl: Point2, br: Point2, stones: Vec<Stone>) -> Result<usize> {
+    let inside = |c: Frac, low: i64, high: i64| Frac::from(low) <= c && c <= Frac::from(high);
+    Ok((0..stones.len() - 1)
+        .flat_map(|i| {
+            let stones = &stones;
+            (i + 1..stones.len()).filter(move |&j| {
+                intercept(&stones[i], &stones[j])
+                    .is_some_and(|(x, y)| inside(x, tl.x, br.x) && inside(y, tl.y, br.y))
+            })
+        })
+        .count())
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::ops::Add;
+use std::ops::Mul;
+use z3::ast::Ast;
+use z3::{ast, Config, Context, Solver};
+
+pub fn process(stones: Vec<Stone>) -> Result<usize> {
+    let ctx = &Context::new(&Config::default());
+    let solver = Solver::new(ctx);
+    let px = &z3::ast::Int::new_const(ctx, "px");
+    let py = &z3::ast::Int::new_const(ctx, "py");
+    let pz = &z3::ast::Int::new_const(ctx, "pz");
+    let vx = &z3::ast::Int::new_const(ctx, "vx");
+    let vy = &z3::ast::Int::new_const(ctx, "vy");
+    let vz = &z3::ast::Int::new_const(ctx, "vz");
+    for (i, stone) in stones.into_iter().enumerate() {
+        let ti = z3::ast::Int::new_const(ctx, format!("t{}", i));
+        solver.assert(
+            &ast::Int::from_i64(ctx, stone.0.x)
+                .add(&ast::Int::from_i64(ctx, stone.1.x).mul(&ti))
+                ._eq(&px.add(&vx.mul(&ti))),
+        );
+        solver.assert(
+            &ast::Int::from_i64(ctx, stone.0.y)
+                .add(&ast::Int::from_i64(ctx, stone.1.y).mul(&ti))
+                ._eq(&py.add(&vy.mul(&ti))),
+        );
+        solver.assert(
+            &ast::Int::from_i64(ctx, stone.0.z)
+                .add(&ast::Int::from_i64(ctx, stone.1.z).mul(&ti))
+                ._eq(&pz.add(&vz.mul(&ti))),
+        );
+    }
+    if solver.check() != z3::SatResult::Sat {
+        let reason = "no rock trajectory hits all hailstones";
+        return Err(Error::no_solution(reason).into());
+    }
+    let model = solver
+        .get_model()
+        .ok_or_else(|| Error::no_solution("z3 has no model"))?;
+    let solution = model
+        .eval(&px.add(py.add(pz)), true)
+        .ok_or_else(|| Error::no_solution("z3 could not evaluate the solution"))?;
+    let solution_str = format!("{}", solution);
+    solution_str.parse().map_err(Report::new)
+}
@@ -4,6 +4,9 @@
 
 pub use aoc::*;
 
+pub mod day24a;
+pub mod day24b;
+
 pub const EXAMPLE: &str = "19, 13, 30 @ -2,  1, -2
 18, 19, 22 @ -1, -1, -2
 20, 25, 34 @ -2, -2, -4
@@ -11,15 +14,16 @@ pub const EXAMPLE: &str = "19, 13, 30 @ -2,  1, -2
 20, 19, 15 @  1, -5, -3
 ";
 
-pub type Xyz = (i64, i64, i64);
-pub type Stone = (Xyz, Xyz, usize);
+pub use aoc::geom::{Point2, Point3};
+
+pub type Stone = (Point3, Point3, usize);
 
 pub mod parser {
     use aoc::parser::*;
 
     use super::*;
 
-    fn xyz(input: &str) -> IResult<&str, Xyz> {
+    fn xyz(input: &str) -> IResult<&str, Point3> {
         let (input, x) = character::i64(input)?;
         let (input, _) = tag(",")(input)?;
         let (input, _) = character::space1(input)?;
@@ -27,7 +31,7 @@ pub mod parser {
         let (input, _) = tag(",")(input)?;
         let (input, _) = character::space1(input)?;
         let (input, z) = character::i64(input)?;
-        Ok((input, (x, y, z)))
+        Ok((input, Point3::new(x, y, z)))
     }
 
     fn line(input: &str) -> IResult<&str, Stone> {
@@ -55,3 +59,86 @@ fn test() -> Result<()> {
     assert_eq!(input.len(), 5);
     Ok(())
 }
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day24 {
+    pub tl: Point2,
+    pub br: Point2,
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Day24 {
+            tl: Point2::new(200000000000000, 200000000000000),
+            br: Point2::new(400000000000000, 400000000000000),
+        }
+    }
+}
+
+impl Solver for Day24 {
+    const DAY: u32 = 24;
+    type Input = Vec<Stone>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(input.iter().map(|(pos, vel, _)| {
+            format!(
+                "{}, {}, {} @ {:>2}, {:>2}, {:>2}",
+                pos.x, pos.y, pos.z, vel.x, vel.y, vel.z
+            )
+        }))
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day24a::process(self.tl, self.br, input)?.into())
+    }
+
+    fn part2(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day24b::process(input)?.into())
+    }
+}
+
+aoc::examples! {
+    Day24;
+    A(Day24 { tl: Point2::new(7, 7), br: Point2::new(27, 27) }): EXAMPLE => 2;
+    B: EXAMPLE => 47;
+}
+
+impl prop::Generate for Day24 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // Stones that all collide with the same rock, at distinct times
+        let rock_pos = Point3::new(
+            g.range(300..=1000),
+            g.range(300..=1000),
+            g.range(300..=1000),
+        );
+        let rock_vel = Point3::new(g.range(-5..=5), g.range(-5..=5), g.range(-5..=5));
+        let len = g.range(3..=5);
+        let mut times = (1..=20).collect::<Vec<i64>>();
+        g.shuffle(&mut times);
+        times[..len]
+            .iter()
+            .enumerate()
+            .map(|(i, &t)| {
+                let vel = loop {
+                    let vel = Point3::new(g.range(-5..=5), g.range(-5..=5), g.range(-5..=5));
+                    if vel != rock_vel {
+                        break vel;
+                    }
+                };
+                (rock_pos + (rock_vel - vel) * t, vel, i)
+            })
+            .collect()
+    }
+}
+
+aoc::properties!(
+    Day24,
+    Day24 {
+        tl: Point2::new(100, 100),
+        br: Point2::new(1000, 1000)
+    }
+);
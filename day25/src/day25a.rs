@@ -0,0 +1,59 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use aoc::graph::{self, Graph, NodeId};
+
+/// Counts how many times each edge is used by the shortest paths from
+/// `start` to the other nodes
+fn count_edges(graph: &Graph<Node>, ecount: &mut BTreeMap<(NodeId, NodeId), usize>, start: NodeId) {
+    let reached = graph::bfs(start, |&node| graph.neighbors(node).collect::<Vec<_>>());
+    for &end in reached.iter() {
+        let mut node = end;
+        while let Some(&parent) = reached.parent(&node) {
+            *ecount
+                .entry((node.min(parent), node.max(parent)))
+                .or_default() += 1;
+            node = parent;
+        }
+    }
+}
+
+pub fn process(input: Vec<(Node, Vec<Node>)>) -> Result<usize> {
+    let edges = input
+        .into_iter()
+        .flat_map(|(node, connected)| {
+            connected
+                .into_iter()
+                .map(move |other| (node.min(other), node.max(other)))
+        })
+        .collect::<BTreeSet<_>>();
+    let mut graph = Graph::<Node>::new();
+    for (node1, node2) in edges {
+        graph.add_undirected_edge(node1, node2, ());
+    }
+    // The most used edges are the ones that connect the groups
+    for _ in 0..3 {
+        let mut ecount = BTreeMap::new();
+        for node in 0..graph.len() {
+            count_edges(&graph, &mut ecount, node);
+        }
+        let (&(n1, n2), _) = ecount
+            .iter()
+            .max_by_key(|(_, count)| **count)
+            .ok_or_else(|| Error::no_solution("no wires to cut"))?;
+        graph.remove_edge(n1, n2);
+        graph.remove_edge(n2, n1);
+    }
+    let groups = graph.components();
+    let [group1, group2] = groups.as_slice() else {
+        let reason = format!("cutting 3 wires left {} groups", groups.len());
+        return Err(Error::no_solution(reason).into());
+    };
+    Ok(group1.len() * group2.len())
+}
@@ -4,6 +4,11 @@
 
 pub use aoc::*;
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+pub mod day25a;
+
 pub const EXAMPLE: &str = "jqt: rhn xhk nvd
 rsh: frs pzl lsr
 xhk: hfx
@@ -27,8 +32,14 @@ pub mod parser {
     use super::*;
 
     fn name(input: &str) -> IResult<&str, Node> {
-        let (input, name_str) = character::alpha1(input)?;
-        Ok((input, name_str.try_into().unwrap()))
+        let (rest, name) = character::alpha1(input)?;
+        match Node::try_from(name) {
+            Ok(node) => Ok((rest, node)),
+            Err(_) => {
+                let e = aoc::Error::shape(format!("component name {} is too long", name));
+                fail(input, e.to_string())
+            }
+        }
     }
 
     fn line(input: &str) -> IResult<&str, (Node, Vec<Node>)> {
@@ -48,5 +59,83 @@ pub mod parser {
 fn test() -> Result<()> {
     let input = parser::parse(EXAMPLE.as_bytes())?;
     assert_eq!(input.len(), 13);
+    let e = parser::parse("jqt: rhnx\n".as_bytes()).unwrap_err();
+    assert!(error::is_bad_input(&e));
+    assert!(e.to_string().contains("component name rhnx is too long"));
     Ok(())
 }
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Day25;
+
+impl Solver for Day25 {
+    const DAY: u32 = 25;
+    const PARTS: &'static [Part] = &[Part::A];
+    type Input = Vec<(Node, Vec<Node>)>;
+
+    fn parse(&self, bufin: impl BufRead) -> Result<Self::Input> {
+        parser::parse(bufin)
+    }
+
+    fn unparse(&self, input: &Self::Input) -> String {
+        unparse::lines(
+            input
+                .iter()
+                .map(|(node, others)| format!("{}: {}", node, unparse::join(others, " "))),
+        )
+    }
+
+    fn part1(&self, input: Self::Input) -> Result<Answer> {
+        Ok(day25a::process(input)?.into())
+    }
+
+    fn part2(&self, _input: Self::Input) -> Result<Answer> {
+        Err(Error::no_part(Self::DAY, Part::B).into())
+    }
+}
+
+aoc::examples! {
+    Day25;
+    A: EXAMPLE => 54;
+}
+
+impl prop::Generate for Day25 {
+    fn generate(&self, g: &mut prop::Gen) -> Self::Input {
+        // Two complete graphs connected by three edges
+        let mut names = BTreeSet::new();
+        let mut group = |g: &mut prop::Gen| {
+            let len = g.range(5..=5 + g.size() / 4);
+            let mut nodes = vec![];
+            while nodes.len() < len {
+                let name = g.string(3, "abcdefghijklmnopqrstuvwxyz");
+                if names.insert(name.clone()) {
+                    nodes.push(Node::try_from(name.as_str()).unwrap());
+                }
+            }
+            nodes
+        };
+        let (group1, group2) = (group(g), group(g));
+        let mut edges = vec![];
+        for group in [&group1, &group2] {
+            for (i, a) in group.iter().enumerate() {
+                edges.extend(group[i + 1..].iter().map(|b| (*a, *b)));
+            }
+        }
+        let mut cut = group2.clone();
+        g.shuffle(&mut cut);
+        for b in &cut[..3] {
+            edges.push((*g.choose(&group1), *b));
+        }
+        // Each edge is listed by one of its nodes
+        let mut lines = BTreeMap::<Node, Vec<Node>>::new();
+        for (a, b) in edges {
+            let (a, b) = if g.bool() { (a, b) } else { (b, a) };
+            lines.entry(a).or_default().push(b);
+        }
+        let mut lines = lines.into_iter().collect::<Vec<_>>();
+        g.shuffle(&mut lines);
+        lines
+    }
+}
+
+aoc::properties!(Day25);
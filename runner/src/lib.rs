@@ -0,0 +1,55 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+pub use aoc::*;
+
+/// All the days, with the parameters used for the real inputs.
+pub fn solvers() -> Vec<Box<dyn DynSolver>> {
+    vec![
+        Box::new(day01::Day01),
+        Box::new(day02::Day02),
+        Box::new(day03::Day03),
+        Box::new(day04::Day04),
+        Box::new(day05::Day05),
+        Box::new(day06::Day06),
+        Box::new(day07::Day07),
+        Box::new(day08::Day08),
+        Box::new(day09::Day09),
+        Box::new(day10::Day10),
+        Box::new(day11::Day11::default()),
+        Box::new(day12::Day12),
+        Box::new(day13::Day13),
+        Box::new(day14::Day14),
+        Box::new(day15::Day15),
+        Box::new(day16::Day16),
+        Box::new(day17::Day17),
+        Box::new(day18::Day18),
+        Box::new(day19::Day19),
+        Box::new(day20::Day20),
+        Box::new(day21::Day21::default()),
+        Box::new(day22::Day22),
+        Box::new(day23::Day23),
+        Box::new(day24::Day24::default()),
+        Box::new(day25::Day25),
+    ]
+}
+
+pub fn find(day: u32) -> Option<Box<dyn DynSolver>> {
+    solvers().into_iter().find(|s| s.day() == day)
+}
+
+#[test]
+fn test_registry() -> Result<()> {
+    let solvers = solvers();
+    // Days are registered in order, without gaps
+    let days = solvers.iter().map(|s| s.day()).collect::<Vec<_>>();
+    assert_eq!(days, (1..=days.len() as u32).collect::<Vec<_>>());
+    assert!(solvers.iter().all(|s| !s.parts().is_empty()));
+    let day01 = find(1).ok_or_eyre("day01 not found")?;
+    assert_eq!(
+        day01.run(Part::A, &mut day01::EXAMPLE1.as_bytes())?,
+        Answer::from(142_u32)
+    );
+    Ok(())
+}
@@ -0,0 +1,263 @@
+// Copyright (C) 2023 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use runner::*;
+
+const USAGE: &str =
+    "usage: aoc run <day> <part> [--bench <runs>] [--record] [--format text|json|tsv] [--log <filter>] [input-file]
+       aoc run all [--parallel] [inputs-dir]  (inputs from <inputs-dir>/dayNN.txt)
+       aoc history compare <baseline-commit> [commit] [--threshold <percent>]";
+
+/// Slowdown, in percent, above which a benchmark is a regression
+const THRESHOLD: f64 = 10.0;
+
+/// Runs a part, returning the answer, the time it took and its check
+fn run_one(
+    solver: &dyn DynSolver,
+    part: Part,
+    path: &Path,
+    answers: &Answers,
+) -> Result<(Answer, Duration, Verdict)> {
+    let input = fs::read_to_string(path).map_err(|e| eyre!("{}: {}", path.display(), e))?;
+    let start = Instant::now();
+    let answer = solver.run(part, &mut input.as_bytes())?;
+    let elapsed = start.elapsed();
+    let verdict = answers.check(solver.day(), part, &input_hash(&input), &answer.to_string());
+    Ok((answer, elapsed, verdict))
+}
+
+/// Description of a failed run, telling bad input apart from the
+/// errors of the solvers
+fn failure(e: &Report) -> String {
+    let kind = if error::is_bad_input(e) {
+        "bad input"
+    } else {
+        "error"
+    };
+    format!("{}: {}", kind, e)
+}
+
+fn run_all(dir: &Path) -> Result<()> {
+    let answers = Answers::load(&answers::answers_path())?;
+    let start = Instant::now();
+    let mut failed = 0;
+    for solver in solvers() {
+        let path = dir.join(format!("day{:02}.txt", solver.day()));
+        for &part in solver.parts() {
+            match run_one(solver.as_ref(), part, &path, &answers) {
+                Ok((answer, elapsed, verdict)) => {
+                    if verdict.is_fail() {
+                        failed += 1;
+                    }
+                    let elapsed = bench::duration(elapsed);
+                    println!(
+                        "{}: {} ({}) {}",
+                        solver.name(part),
+                        answer,
+                        elapsed,
+                        verdict
+                    );
+                }
+                Err(e) => {
+                    failed += 1;
+                    println!("{}: {}", solver.name(part), failure(&e));
+                }
+            }
+        }
+    }
+    println!("Elapsed: {}", elapsed(&start));
+    if failed > 0 {
+        return Err(eyre!("{} solvers failed", failed));
+    }
+    Ok(())
+}
+
+/// CPU time used by the process so far, in all threads
+fn cpu_time() -> Duration {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+    // SAFETY: getrusage only writes to the struct we pass
+    let usage = unsafe {
+        libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr());
+        usage.assume_init()
+    };
+    let time = |t: libc::timeval| Duration::new(t.tv_sec as u64, t.tv_usec as u32 * 1000);
+    time(usage.ru_utime) + time(usage.ru_stime)
+}
+
+/// Runs all the parts at the same time on the rayon pool, and prints
+/// a table with the results
+fn run_parallel(dir: &Path) -> Result<()> {
+    let answers = Answers::load(&answers::answers_path())?;
+    let solvers = solvers();
+    let jobs = solvers
+        .iter()
+        .flat_map(|s| s.parts().iter().map(move |&part| (s.as_ref(), part)))
+        .collect::<Vec<_>>();
+    let cpu_start = cpu_time();
+    let start = Instant::now();
+    let results = jobs
+        .par_iter()
+        .map(|&(solver, part)| {
+            let path = dir.join(format!("day{:02}.txt", solver.day()));
+            (solver.name(part), run_one(solver, part, &path, &answers))
+        })
+        .collect::<Vec<_>>();
+    let wall = start.elapsed();
+    let cpu = cpu_time() - cpu_start;
+    let mut failed = 0;
+    let mut rows = vec![[
+        "solver".to_string(),
+        "answer".into(),
+        "time".into(),
+        "check".into(),
+    ]];
+    for (name, result) in results {
+        let row = match result {
+            Ok((answer, elapsed, verdict)) => {
+                if verdict.is_fail() {
+                    failed += 1;
+                }
+                let elapsed = bench::duration(elapsed);
+                [name, answer.to_string(), elapsed, verdict.to_string()]
+            }
+            Err(e) => {
+                failed += 1;
+                [name, "-".into(), "-".into(), failure(&e)]
+            }
+        };
+        rows.push(row);
+    }
+    let widths = (0..3)
+        .map(|i| rows.iter().map(|r| r[i].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+    for row in &rows {
+        println!(
+            "{:w0$}  {:>w1$}  {:>w2$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2]
+        );
+    }
+    println!("Wall time: {}", bench::duration(wall));
+    println!("CPU time: {}", bench::duration(cpu));
+    if failed > 0 {
+        return Err(eyre!("{} solvers failed", failed));
+    }
+    Ok(())
+}
+
+/// Compares the benchmarks in the history of two commits, failing if
+/// any part got slower by more than `threshold` percent
+fn compare_history(baseline: &str, current: Option<&str>, threshold: f64) -> Result<()> {
+    // Revisions like HEAD~1 become the hashes in the history; the ones
+    // that git doesn't know, like <hash>-dirty, are used as they are
+    let commit = |rev: &str| history::resolve(rev).unwrap_or_else(|| rev.to_string());
+    let baseline = commit(baseline);
+    let current = current.map(commit);
+    let entries = history::load(&history::history_path())?;
+    let machine = history::machine_id();
+    let comparisons = history::compare(&entries, &machine, &baseline, current.as_deref());
+    if comparisons.is_empty() {
+        return Err(eyre!(
+            "no benchmarks of {} to compare on machine {}",
+            baseline,
+            machine
+        ));
+    }
+    let mut regressions = 0;
+    for c in &comparisons {
+        let flag = if c.is_regression(threshold) {
+            regressions += 1;
+            "  REGRESSION"
+        } else {
+            ""
+        };
+        println!(
+            "day{:02}{}: {} -> {} ({:+.1}%){}",
+            c.day,
+            c.part,
+            bench::duration(c.baseline),
+            bench::duration(c.current),
+            c.change(),
+            flag
+        );
+    }
+    if regressions > 0 {
+        return Err(eyre!(
+            "{} solvers more than {}% slower",
+            regressions,
+            threshold
+        ));
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+    match args[..] {
+        ["run", "all", ref rest @ ..] => {
+            color_eyre::install()?;
+            logging::init(None)?;
+            let parallel = rest.contains(&"--parallel");
+            let dirs = rest
+                .iter()
+                .filter(|a| **a != "--parallel")
+                .collect::<Vec<_>>();
+            let dir = match dirs[..] {
+                [] => Path::new("inputs"),
+                [dir] if !dir.starts_with("--") => Path::new(dir),
+                _ => return Err(eyre!("{}", USAGE)),
+            };
+            if parallel {
+                run_parallel(dir)
+            } else {
+                run_all(dir)
+            }
+        }
+        ["run", day, part, ref rest @ ..] => {
+            color_eyre::install()?;
+            let day = day.parse::<u32>()?;
+            let part = part.parse::<Part>()?;
+            let options = Options::parse(rest)?;
+            logging::init(options.log.as_deref())?;
+            let solver = find(day).ok_or_else(|| eyre!("no solver for day {}", day))?;
+            run_main(solver.as_ref(), part, &options)
+        }
+        ["history", "compare", ref rest @ ..] => {
+            color_eyre::install()?;
+            let mut threshold = THRESHOLD;
+            let mut commits = vec![];
+            let mut args = rest.iter();
+            while let Some(&arg) = args.next() {
+                match arg {
+                    "--threshold" => {
+                        let percent = args
+                            .next()
+                            .ok_or_else(|| eyre!("--threshold requires a percentage"))?;
+                        threshold = percent.parse()?;
+                    }
+                    commit if !commit.starts_with("--") => commits.push(commit),
+                    _ => return Err(eyre!("{}", USAGE)),
+                }
+            }
+            match commits[..] {
+                [baseline] => compare_history(baseline, None, threshold),
+                [baseline, current] => compare_history(baseline, Some(current), threshold),
+                _ => Err(eyre!("{}", USAGE)),
+            }
+        }
+        _ => Err(eyre!("{}", USAGE)),
+    }
+}